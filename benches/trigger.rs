@@ -0,0 +1,35 @@
+//! Benchmarks serializing a large `HxResponseTrigger` payload. Compare the
+//! serializers by running it with and without the `simd-json` feature:
+//!
+//! ```sh
+//! cargo bench --features serde
+//! cargo bench --features simd-json
+//! ```
+
+use axum_core::response::IntoResponse;
+use axum_htmx::response::{HxEvent, HxResponseTrigger};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use serde_json::json;
+
+fn large_trigger() -> HxResponseTrigger {
+    let rows = (0..500)
+        .map(|i| json!({"id": i, "name": format!("row {i}"), "tags": ["a", "b", "c"]}))
+        .collect::<Vec<_>>();
+
+    HxResponseTrigger::normal([HxEvent::new_with_data("rows-updated", rows).unwrap()])
+}
+
+fn bench_trigger(c: &mut Criterion) {
+    let trigger = large_trigger();
+
+    c.bench_function("serialize large trigger", |b| {
+        b.iter_batched(
+            || trigger.clone(),
+            |trigger| black_box((trigger, ()).into_response()),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_trigger);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+//! Renders form validation errors into a region of the page.
+//!
+//! A failed submission responds with `422 Unprocessable Entity` and retargets
+//! the errors into `#errors` with [`HxValidationError`], while a successful one
+//! is swapped into the form's own target, `#result`.
+//!
+//! Run with `cargo run --example validation --features serde`.
+
+use axum::{
+    body::{to_bytes, Body},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Form, Router,
+};
+use axum_htmx::{response::HxValidationError, HX_REQUEST, HX_RESWAP, HX_RETARGET};
+use http::{header::CONTENT_TYPE, Request, StatusCode};
+use serde::Deserialize;
+use tower::ServiceExt;
+
+const PAGE: &str = r##"<!doctype html>
+<html>
+<head>
+  <script src="https://unpkg.com/htmx.org@2"></script>
+  <!-- htmx 2 does not swap 4xx responses unless told to. -->
+  <meta name="htmx-config" content='{"responseHandling": [
+    {"code": "204", "swap": false},
+    {"code": "[23]..", "swap": true},
+    {"code": "422", "swap": true},
+    {"code": "[45]..", "swap": false, "error": true}
+  ]}'>
+</head>
+<body>
+  <form hx-post="/signup" hx-target="#result">
+    <input name="email" type="email">
+    <input name="password" type="password">
+    <button>Sign up</button>
+  </form>
+  <ul id="errors"></ul>
+  <div id="result"></div>
+</body>
+</html>"##;
+
+#[derive(Debug, Deserialize)]
+struct Signup {
+    email: String,
+    password: String,
+}
+
+impl Signup {
+    fn errors(&self) -> Vec<&'static str> {
+        let mut errors = Vec::new();
+        if !self.email.contains('@') {
+            errors.push("Email must contain an @");
+        }
+        if self.password.len() < 8 {
+            errors.push("Password must be at least 8 characters long");
+        }
+
+        errors
+    }
+}
+
+async fn page() -> Html<&'static str> {
+    Html(PAGE)
+}
+
+async fn signup(Form(signup): Form<Signup>) -> Response {
+    let errors = signup.errors();
+    if !errors.is_empty() {
+        let list = errors
+            .iter()
+            .map(|error| format!("<li>{error}</li>"))
+            .collect::<String>();
+
+        // 422, HX-Retarget: #errors, HX-Reswap: innerHTML
+        return HxValidationError::new("#errors", Html(list)).into_response();
+    }
+
+    // 200, swapped into #result as usual.
+    Html(format!("<p>Welcome, {}!</p>", signup.email)).into_response()
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/", get(page))
+        .route("/signup", post(signup))
+}
+
+async fn submit(form: &'static str) -> (Response, String) {
+    let req = Request::post("/signup")
+        .header(HX_REQUEST, "true")
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))
+        .unwrap();
+
+    let (parts, body) = app().oneshot(req).await.unwrap().into_parts();
+    let body = to_bytes(body, usize::MAX).await.unwrap();
+
+    (
+        Response::from_parts(parts, Body::empty()),
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let (res, body) = submit("email=htmx&password=short").await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(res.headers()[HX_RETARGET], "#errors");
+    assert_eq!(res.headers()[HX_RESWAP], "innerHTML");
+    println!("422: {body}");
+
+    let (res, body) = submit("email=htmx%40example.com&password=hunter22").await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(res.headers().get(HX_RETARGET).is_none());
+    println!("200: {body}");
+}
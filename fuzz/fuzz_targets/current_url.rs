@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some(url) = axum_htmx::fuzzing::current_url(data) else {
+        return;
+    };
+
+    // Normalizing an already normalized url must not change it.
+    let again = axum_htmx::extract::HxCurrentUrl(Some(url.clone())).normalized();
+    assert_eq!(again.as_ref(), Some(&url));
+});
@@ -1,16 +1,70 @@
 use std::{error, fmt};
 
 use axum_core::response::IntoResponse;
-use http::{header::InvalidHeaderValue, StatusCode};
+use http::{
+    header::{InvalidHeaderName, InvalidHeaderValue},
+    StatusCode,
+};
 
 /// Error enum of all possible errors.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HxError {
+    InvalidHeaderName(InvalidHeaderName),
     InvalidHeaderValue(InvalidHeaderValue),
+    InvalidReswap(&'static str),
+    InvalidRedirect(&'static str),
+    InvalidSseEvent(&'static str),
+    Custom(String),
 
     #[cfg(feature = "serde")]
     #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
     Json(serde_json::Error),
+
+    /// The data of the trigger `event` could not be serialized.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    TriggerSerialization {
+        event: String,
+        source: serde_json::Error,
+    },
+}
+
+impl HxError {
+    /// Creates a custom error from any displayable value.
+    ///
+    /// This allows converting application errors into an `HxError`, e.g. when
+    /// building responders from fallible application code.
+    pub fn custom(msg: impl fmt::Display) -> Self {
+        Self::Custom(msg.to_string())
+    }
+
+    /// Returns a message that is safe to show to clients.
+    ///
+    /// Unlike [`Display`](fmt::Display), it does not include details of the
+    /// underlying error, which may reveal internals of the application. This
+    /// message is used as the body of the [`IntoResponse`] implementation.
+    pub fn client_message(&self) -> &'static str {
+        match self {
+            HxError::InvalidHeaderName(_) | HxError::InvalidHeaderValue(_) => {
+                "Invalid htmx response header"
+            }
+            HxError::InvalidReswap(_) => "Invalid htmx swap",
+            HxError::InvalidRedirect(_) => "Invalid htmx redirect",
+            HxError::InvalidSseEvent(_) => "Invalid server-sent event",
+            HxError::Custom(_) => "Internal server error",
+            #[cfg(feature = "serde")]
+            HxError::Json(_) | HxError::TriggerSerialization { .. } => {
+                "Failed to serialize htmx response header"
+            }
+        }
+    }
+}
+
+impl From<InvalidHeaderName> for HxError {
+    fn from(value: InvalidHeaderName) -> Self {
+        Self::InvalidHeaderName(value)
+    }
 }
 
 impl From<InvalidHeaderValue> for HxError {
@@ -30,17 +84,69 @@ impl From<serde_json::Error> for HxError {
 impl fmt::Display for HxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            HxError::InvalidHeaderName(err) => write!(f, "Invalid header name: {err}"),
             HxError::InvalidHeaderValue(err) => write!(f, "Invalid header value: {err}"),
+            HxError::InvalidReswap(msg) => write!(f, "Invalid reswap: {msg}"),
+            HxError::InvalidRedirect(msg) => write!(f, "Invalid redirect: {msg}"),
+            HxError::InvalidSseEvent(msg) => write!(f, "Invalid server-sent event: {msg}"),
+            HxError::Custom(msg) => f.write_str(msg),
             #[cfg(feature = "serde")]
             HxError::Json(err) => write!(f, "Json: {err}"),
+            #[cfg(feature = "serde")]
+            HxError::TriggerSerialization { event, source } => {
+                write!(f, "Failed to serialize data of event `{event}`: {source}")
+            }
         }
     }
 }
 
-impl error::Error for HxError {}
+impl error::Error for HxError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "serde")]
+            HxError::TriggerSerialization { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
 
 impl IntoResponse for HxError {
     fn into_response(self) -> axum_core::response::Response {
-        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+        (StatusCode::INTERNAL_SERVER_ERROR, self.client_message()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_custom_error() {
+        let err = HxError::custom(format_args!("user {} not found", 42));
+        assert_eq!(err.to_string(), "user 42 not found");
+
+        let res = err.into_response();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body(res).await, "Internal server error");
+    }
+
+    #[tokio::test]
+    async fn test_response_uses_client_message() {
+        let err = HxError::from(http::HeaderValue::from_str("bad\nvalue").unwrap_err());
+        assert_eq!(
+            err.to_string(),
+            "Invalid header value: failed to parse header value"
+        );
+
+        let res = err.into_response();
+        assert_eq!(body(res).await, "Invalid htmx response header");
+    }
+
+    async fn body(res: axum_core::response::Response) -> String {
+        let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        String::from_utf8(bytes.to_vec()).unwrap()
     }
 }
@@ -1,14 +1,31 @@
 //! Axum extractors for htmx request headers.
 
+use std::{fmt, str::FromStr};
+
 use async_trait::async_trait;
-use axum_core::extract::FromRequestParts;
-use http::request::Parts;
+use axum_core::{
+    extract::FromRequestParts,
+    response::{IntoResponse, IntoResponseParts, Response},
+};
+use http::{request::Parts, HeaderMap, StatusCode};
 
 use crate::{
     HX_BOOSTED, HX_CURRENT_URL, HX_HISTORY_RESTORE_REQUEST, HX_PROMPT, HX_REQUEST, HX_TARGET,
     HX_TRIGGER, HX_TRIGGER_NAME,
 };
 
+#[cfg(feature = "strict-extractors")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "strict-extractors")))]
+pub mod strict;
+
+fn header_string(parts: &Parts, name: &str) -> Option<String> {
+    parts
+        .headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
 /// The `HX-Boosted` header.
 ///
 /// This is set when a request is made from an element where its parent has the
@@ -29,11 +46,13 @@ where
     type Rejection = std::convert::Infallible;
 
     async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
-        if parts.headers.contains_key(HX_BOOSTED) {
-            return Ok(HxBoosted(true));
-        } else {
-            return Ok(HxBoosted(false));
-        }
+        Ok(HxBoosted::from_parts(parts))
+    }
+}
+
+impl HxBoosted {
+    fn from_parts(parts: &Parts) -> Self {
+        HxBoosted(parts.headers.contains_key(HX_BOOSTED))
     }
 }
 
@@ -55,23 +74,118 @@ where
     type Rejection = std::convert::Infallible;
 
     async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(HxCurrentUrl::from_parts(parts))
+    }
+}
+
+impl HxCurrentUrl {
+    /// Returns the url with a normalized percent-encoding of its path and
+    /// query, which is useful for comparing urls.
+    ///
+    /// Following RFC 3986, percent-encoded unreserved characters
+    /// (`A-Z a-z 0-9 - . _ ~`) are decoded and the hex digits of all other
+    /// percent-encodings are uppercased. Reserved characters such as `%2F`
+    /// stay encoded, as decoding them would change the meaning of the url.
+    ///
+    /// Returns `None` if there is no url or it contains an invalid
+    /// percent-encoding.
+    pub fn normalized(&self) -> Option<http::Uri> {
+        let url = self.0.as_ref()?;
+        let Some(path_and_query) = url.path_and_query() else {
+            return Some(url.clone());
+        };
+
+        let normalized = normalize_percent_encoding(path_and_query.as_str())?;
+
+        let mut builder = http::Uri::builder();
+        if let Some(scheme) = url.scheme() {
+            builder = builder.scheme(scheme.clone());
+        }
+        if let Some(authority) = url.authority() {
+            builder = builder.authority(authority.clone());
+        }
+
+        builder.path_and_query(normalized).build().ok()
+    }
+
+    fn from_parts(parts: &Parts) -> Self {
         if let Some(url) = parts.headers.get(HX_CURRENT_URL) {
-            let url = url
-                .to_str()
-                .ok()
-                .and_then(|url| url.parse::<http::Uri>().ok());
+            return HxCurrentUrl(extract_current_url(url));
+        }
+
+        HxCurrentUrl(None)
+    }
+}
+
+/// Parses the value of the `HX-Current-Url` header, which comes straight from
+/// the client and must never panic.
+pub(crate) fn extract_current_url(value: &http::HeaderValue) -> Option<http::Uri> {
+    value
+        .to_str()
+        .ok()
+        .and_then(|url| url.parse::<http::Uri>().ok())
+}
+
+fn normalize_percent_encoding(input: &str) -> Option<String> {
+    fn hex(byte: u8) -> Option<u8> {
+        (byte as char).to_digit(16).map(|digit| digit as u8)
+    }
+
+    let bytes = input.as_bytes();
+    let mut output = String::with_capacity(input.len());
 
-            return Ok(HxCurrentUrl(url));
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            output.push(bytes[i] as char);
+            i += 1;
+            continue;
         }
 
-        return Ok(HxCurrentUrl(None));
+        let high = hex(*bytes.get(i + 1)?)?;
+        let low = hex(*bytes.get(i + 2)?)?;
+        let decoded = high << 4 | low;
+
+        if decoded.is_ascii_alphanumeric() || matches!(decoded, b'-' | b'.' | b'_' | b'~') {
+            output.push(decoded as char);
+        } else {
+            output.push_str(&format!("%{decoded:02X}"));
+        }
+
+        i += 3;
     }
+
+    Some(output)
 }
 
 /// The `HX-History-Restore-Request` header.
 ///
 /// This extractor will always return a value. If the header is not present, it
 /// will return `false`.
+///
+/// htmx sends this header when it misses its local history cache and needs the
+/// full page to restore. The page is usually the same for every restore, so it
+/// is a good candidate for caching instead of being rendered again:
+///
+/// ```
+/// use axum::response::{Html, IntoResponse, Response};
+/// use axum_htmx::extract::HxHistoryRestoreRequest;
+/// use http::{header, HeaderMap, StatusCode};
+///
+/// const PAGE_ETAG: &str = "\"v1\"";
+///
+/// async fn page(restore: HxHistoryRestoreRequest, headers: HeaderMap) -> Response {
+///     if restore.is_restore() {
+///         if headers.get(header::IF_NONE_MATCH).is_some_and(|tag| tag == PAGE_ETAG) {
+///             return StatusCode::NOT_MODIFIED.into_response();
+///         }
+///
+///         return ([(header::ETAG, PAGE_ETAG)], Html("<html>...</html>")).into_response();
+///     }
+///
+///     Html("<div>...</div>").into_response()
+/// }
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct HxHistoryRestoreRequest(pub bool);
 
@@ -83,11 +197,127 @@ where
     type Rejection = std::convert::Infallible;
 
     async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
-        if parts.headers.contains_key(HX_HISTORY_RESTORE_REQUEST) {
-            return Ok(HxHistoryRestoreRequest(true));
-        } else {
-            return Ok(HxHistoryRestoreRequest(false));
+        Ok(HxHistoryRestoreRequest::from_parts(parts))
+    }
+}
+
+impl HxHistoryRestoreRequest {
+    /// Returns `true` if htmx is restoring the history after a cache miss and
+    /// expects a full page.
+    pub fn is_restore(&self) -> bool {
+        self.0
+    }
+
+    fn from_parts(parts: &Parts) -> Self {
+        HxHistoryRestoreRequest(parts.headers.contains_key(HX_HISTORY_RESTORE_REQUEST))
+    }
+}
+
+/// Returns `true` if the request is a history navigation within a boosted
+/// application.
+///
+/// This is the case when both the `HX-Boosted` and `HX-History-Restore-Request`
+/// headers are set, which happens when the user navigates back or forward
+/// after a miss in the local history cache. Such requests should typically be
+/// served a full, cacheable page.
+pub fn is_boosted_history_nav(parts: &Parts) -> bool {
+    let HxBoosted(boosted) = HxBoosted::from_parts(parts);
+    let HxHistoryRestoreRequest(restore) = HxHistoryRestoreRequest::from_parts(parts);
+
+    boosted && restore
+}
+
+/// Navigation information of a boosted application.
+///
+/// Combines the `HX-Boosted`, `HX-Current-Url` and `HX-History-Restore-Request`
+/// headers, which together are enough to make SPA-like routing decisions on
+/// the server.
+///
+/// This extractor will always return a value, see the individual extractors
+/// for the defaults of each field.
+#[derive(Debug, Clone)]
+pub struct HxBoostedNavigation {
+    /// Whether the request was made by a boosted element.
+    pub boosted: bool,
+    /// The current url of the browser.
+    pub current_url: Option<http::Uri>,
+    /// Whether the request is a history restoration after a cache miss.
+    pub restore: bool,
+}
+
+impl HxBoostedNavigation {
+    /// Returns `true` if the response should be a full page, i.e. when the
+    /// request did not come from a boosted element or htmx is restoring the
+    /// history.
+    pub fn should_render_full_page(&self) -> bool {
+        !self.boosted || self.restore
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxBoostedNavigation
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let HxBoosted(boosted) = HxBoosted::from_parts(parts);
+        let HxCurrentUrl(current_url) = HxCurrentUrl::from_parts(parts);
+        let HxHistoryRestoreRequest(restore) = HxHistoryRestoreRequest::from_parts(parts);
+
+        return Ok(HxBoostedNavigation {
+            boosted,
+            current_url,
+            restore,
+        });
+    }
+}
+
+/// The kind of interaction that made the request.
+///
+/// Combines the request method with the `HX-Request` header. htmx issues `GET`
+/// requests for elements like links and `hx-get`, and submits forms or
+/// `hx-post`, `hx-put`, `hx-patch` and `hx-delete` elements with the respective
+/// method.
+///
+/// This extractor will always return a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HxMethodHint {
+    /// A `GET` or `HEAD` request made by htmx, e.g. loading a partial or a
+    /// boosted link.
+    HtmxGet,
+    /// A request made by htmx with any other method, e.g. a form submission.
+    HtmxSubmit(http::Method),
+    /// A request not made by htmx.
+    Plain(http::Method),
+}
+
+impl HxMethodHint {
+    /// Returns `true` if the request was made by htmx.
+    pub fn is_htmx(&self) -> bool {
+        !matches!(self, HxMethodHint::Plain(_))
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxMethodHint
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(HX_REQUEST) {
+            return Ok(HxMethodHint::Plain(parts.method.clone()));
+        }
+
+        if parts.method == http::Method::GET || parts.method == http::Method::HEAD {
+            return Ok(HxMethodHint::HtmxGet);
         }
+
+        return Ok(HxMethodHint::HtmxSubmit(parts.method.clone()));
     }
 }
 
@@ -119,6 +349,206 @@ where
     }
 }
 
+/// The `HX-Prompt` header, limited to `MAX` bytes.
+///
+/// This works like [`HxPrompt`], but rejects the request with
+/// `400 Bad Request` if the prompt is longer than `MAX` bytes or contains
+/// characters that are not visible ASCII. Note that the header size limits of
+/// the server still apply before this extractor runs.
+///
+/// If the header is not present, it will return `None`.
+#[derive(Debug, Clone)]
+pub struct HxPromptBounded<const MAX: usize>(pub Option<String>);
+
+#[async_trait]
+impl<S, const MAX: usize> FromRequestParts<S> for HxPromptBounded<MAX>
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        if let Some(prompt) = parts.headers.get(HX_PROMPT) {
+            if prompt.len() > MAX {
+                return Err(HxHeaderRejection::new(
+                    HX_PROMPT,
+                    HxHeaderRejectionReason::TooLong { max: MAX },
+                ));
+            }
+
+            let prompt = prompt
+                .to_str()
+                .map_err(|_| HxHeaderRejection::new(HX_PROMPT, HxHeaderRejectionReason::Invalid))?;
+
+            return Ok(HxPromptBounded(Some(prompt.to_string())));
+        }
+
+        return Ok(HxPromptBounded(None));
+    }
+}
+
+/// Rejection used by extractors that require an htmx request header to be
+/// present or valid, such as [`HxPromptBounded`].
+///
+/// Responds with `400 Bad Request` and a message naming the header and the
+/// reason of the rejection.
+#[derive(Debug, Clone)]
+pub struct HxHeaderRejection {
+    header: &'static str,
+    reason: HxHeaderRejectionReason,
+}
+
+/// Why an [`HxHeaderRejection`] happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HxHeaderRejectionReason {
+    /// The header is not present.
+    Missing,
+    /// The header is present but its value could not be parsed.
+    Invalid,
+    /// The header value is longer than `max` bytes.
+    TooLong { max: usize },
+}
+
+impl HxHeaderRejection {
+    /// Creates new rejection for `header`.
+    pub fn new(header: &'static str, reason: HxHeaderRejectionReason) -> Self {
+        Self { header, reason }
+    }
+
+    /// Returns the name of the rejected header.
+    pub fn header(&self) -> &'static str {
+        self.header
+    }
+
+    /// Returns why the header was rejected.
+    pub fn reason(&self) -> HxHeaderRejectionReason {
+        self.reason
+    }
+
+    /// Returns the status code of the response.
+    pub fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl fmt::Display for HxHeaderRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            HxHeaderRejectionReason::Missing => write!(f, "Missing `{}` header", self.header),
+            HxHeaderRejectionReason::Invalid => write!(f, "Invalid `{}` header", self.header),
+            HxHeaderRejectionReason::TooLong { max } => write!(
+                f,
+                "`{}` header exceeds the maximum length of {max} bytes",
+                self.header
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HxHeaderRejection {}
+
+impl IntoResponse for HxHeaderRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+/// Context of a prompt or confirm dialog.
+///
+/// Combines the `HX-Prompt`, `HX-Trigger` and `HX-Trigger-Name` headers, which
+/// identify both the input of the user and the element that asked for it.
+///
+/// This extractor will always return a value. If a header is not present, its
+/// field will be `None`.
+#[derive(Debug, Clone)]
+pub struct HxPromptContext {
+    /// The user response to an `hx-prompt`.
+    pub prompt: Option<String>,
+    /// The `id` of the triggered element.
+    pub trigger: Option<String>,
+    /// The `name` of the triggered element.
+    pub trigger_name: Option<String>,
+}
+
+impl HxPromptContext {
+    /// Returns the user response to an `hx-prompt`.
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    /// Returns the `id` of the triggered element.
+    pub fn trigger(&self) -> Option<&str> {
+        self.trigger.as_deref()
+    }
+
+    /// Returns the `name` of the triggered element.
+    pub fn trigger_name(&self) -> Option<&str> {
+        self.trigger_name.as_deref()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxPromptContext
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxPromptContext {
+            prompt: header_string(parts, HX_PROMPT),
+            trigger: header_string(parts, HX_TRIGGER),
+            trigger_name: header_string(parts, HX_TRIGGER_NAME),
+        });
+    }
+}
+
+/// Wraps a rejection, adding htmx response headers to its response.
+///
+/// This allows custom extractors to produce htmx aware error responses, e.g.
+/// triggering an event that shows an error toast or retargeting the error
+/// message. `parts` can be any responder, or a tuple of them.
+///
+/// ```
+/// use axum::response::IntoResponse;
+/// use axum_htmx::{
+///     extract::HxRejection,
+///     response::{HxResponseTrigger, HxRetarget},
+/// };
+///
+/// fn with_toast(rejection: impl IntoResponse) -> impl IntoResponse {
+///     HxRejection::new(
+///         rejection,
+///         (HxResponseTrigger::normal(["show-error"]), HxRetarget::from("#errors")),
+///     )
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HxRejection<R, P> {
+    /// The wrapped rejection.
+    pub rejection: R,
+    /// The response headers added to the rejection.
+    pub parts: P,
+}
+
+impl<R, P> HxRejection<R, P> {
+    /// Wraps `rejection`, adding `parts` to its response.
+    pub fn new(rejection: R, parts: P) -> Self {
+        Self { rejection, parts }
+    }
+}
+
+impl<R, P> IntoResponse for HxRejection<R, P>
+where
+    R: IntoResponse,
+    P: IntoResponseParts,
+{
+    fn into_response(self) -> Response {
+        (self.parts, self.rejection).into_response()
+    }
+}
+
 /// The `HX-Request` header.
 ///
 /// This is set on every request made by htmx itself. It won't be present on
@@ -145,12 +575,47 @@ where
     }
 }
 
+/// The raw value of the `HX-Request` header.
+///
+/// htmx always sends `true`, so [`HxRequest`] is sufficient in most cases. This
+/// extractor preserves the value as sent, for clients that may send something
+/// else.
+///
+/// This extractor will always return a value. If the header is not present, it
+/// will return `None`.
+#[derive(Debug, Clone)]
+pub struct HxRequestRaw(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxRequestRaw
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        if let Some(request) = parts.headers.get(HX_REQUEST) {
+            if let Ok(request) = request.to_str() {
+                return Ok(HxRequestRaw(Some(request.to_string())));
+            }
+        }
+
+        return Ok(HxRequestRaw(None));
+    }
+}
+
 /// The `HX-Target` header.
 ///
 /// This is set when a request is made from an element that has the `hx-target`
 /// attribute set. The value will contain the target element's id. If the id
 /// does not exist on the page, the value will be None.
 ///
+/// htmx only sends the header when the target element has an `id`, so a
+/// missing header does not mean that there is no target: the request may still
+/// target an element without an `id`, e.g. `hx-target="closest tr"`. Use
+/// [`HxTargetInfo`] to tell these cases apart from requests that were not made
+/// by htmx at all.
+///
 /// This extractor will always return a value. If the header is not present, it
 /// will return `None`.
 #[derive(Debug, Clone)]
@@ -174,6 +639,72 @@ where
     }
 }
 
+impl HxTarget {
+    /// Returns `true` if the target resolved to an element with an `id`.
+    pub fn resolved(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns `true` if the id is equal to `id`, ignoring ASCII case.
+    ///
+    /// Note that element ids are case-sensitive in htmx and HTML, so this is
+    /// only useful if the application normalizes its ids.
+    pub fn eq_ignore_case(&self, id: &str) -> bool {
+        self.0
+            .as_deref()
+            .is_some_and(|value| value.eq_ignore_ascii_case(id))
+    }
+}
+
+/// The target element of the request.
+///
+/// Combines the `HX-Target` and `HX-Request` headers, which makes it possible to
+/// distinguish a request without a resolved target from a request that was not
+/// made by htmx.
+///
+/// This extractor will always return a value. If a header is not present, its
+/// field will be `None` or `false`.
+#[derive(Debug, Clone)]
+pub struct HxTargetInfo {
+    /// The `id` of the target element.
+    pub id: Option<String>,
+    /// Whether the request was made by htmx.
+    pub is_htmx: bool,
+}
+
+impl HxTargetInfo {
+    /// Returns the `id` of the target element, if it resolved to one.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns `true` if the target resolved to an element with an `id`.
+    pub fn resolved(&self) -> bool {
+        self.id.is_some()
+    }
+
+    /// Returns `true` if the request was made by htmx, but the target has no
+    /// `id` on the page.
+    pub fn unresolved(&self) -> bool {
+        self.is_htmx && self.id.is_none()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTargetInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxTargetInfo {
+            id: header_string(parts, HX_TARGET),
+            is_htmx: parts.headers.contains_key(HX_REQUEST),
+        });
+    }
+}
+
 /// The `HX-Trigger-Name` header.
 ///
 /// This is set when a request is made from an element that has the `hx-trigger`
@@ -203,6 +734,58 @@ where
     }
 }
 
+impl HxTriggerName {
+    /// Returns the name without a trailing `[]`.
+    ///
+    /// Forms commonly name grouped inputs, such as a group of checkboxes, with
+    /// a `[]` suffix, e.g. `tags[]`, to mark that the field has multiple
+    /// values. This returns `tags` for both `tags[]` and `tags`.
+    pub fn base_name(&self) -> Option<&str> {
+        self.0
+            .as_deref()
+            .map(|name| name.strip_suffix("[]").unwrap_or(name))
+    }
+
+    /// Returns `true` if the name ends with `[]`, marking a field with
+    /// multiple values.
+    pub fn is_array(&self) -> bool {
+        self.0.as_deref().is_some_and(|name| name.ends_with("[]"))
+    }
+}
+
+/// The `HX-Trigger-Name` header, parsed into `T`.
+///
+/// This works like [`HxTriggerName`], but parses the name of the triggering
+/// element with [`FromStr`], which allows dispatching on a known set of input
+/// names.
+///
+/// This extractor will always return a value. If the header is not present, or
+/// the extractor fails to parse the name it will return `None`.
+#[derive(Debug, Clone)]
+pub struct HxTriggerNameOf<T>(pub Option<T>);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for HxTriggerNameOf<T>
+where
+    S: Send + Sync,
+    T: FromStr,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        if let Some(trigger_name) = parts.headers.get(HX_TRIGGER_NAME) {
+            let trigger_name = trigger_name
+                .to_str()
+                .ok()
+                .and_then(|trigger_name| trigger_name.parse::<T>().ok());
+
+            return Ok(HxTriggerNameOf(trigger_name));
+        }
+
+        return Ok(HxTriggerNameOf(None));
+    }
+}
+
 /// The `HX-Trigger` header.
 ///
 /// This is set when a request is made from an element that has the `hx-trigger`
@@ -231,3 +814,857 @@ where
         return Ok(HxTrigger(None));
     }
 }
+
+impl HxTrigger {
+    /// Returns `true` if the id is equal to `id`, ignoring ASCII case.
+    ///
+    /// Note that element ids are case-sensitive in htmx and HTML, so this is
+    /// only useful if the application normalizes its ids.
+    pub fn eq_ignore_case(&self, id: &str) -> bool {
+        self.0
+            .as_deref()
+            .is_some_and(|value| value.eq_ignore_ascii_case(id))
+    }
+}
+
+/// Every value of the `HX-Trigger` header.
+///
+/// htmx sends at most one `HX-Trigger` header, but a proxy or a custom client
+/// may send it several times, in which case [`HxTrigger`] only sees the first
+/// value. This extractor collects all of them in order. Values that are not
+/// visible ASCII are skipped.
+///
+/// This extractor will always return a value. If the header is not present, it
+/// will return an empty `Vec`.
+#[derive(Debug, Clone)]
+pub struct HxTriggerAll(pub Vec<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTriggerAll
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let triggers = parts
+            .headers
+            .get_all(HX_TRIGGER)
+            .iter()
+            .filter_map(|trigger| trigger.to_str().ok())
+            .map(str::to_owned)
+            .collect();
+
+        return Ok(HxTriggerAll(triggers));
+    }
+}
+
+/// The triggering element of the request.
+///
+/// Combines the `HX-Trigger` and `HX-Trigger-Name` headers, which contain the
+/// `id` and the `name` of the element respectively.
+///
+/// This extractor will always return a value. If a header is not present, its
+/// field will be `None`.
+#[derive(Debug, Clone)]
+pub struct HxTriggerInfo {
+    /// The `id` of the triggered element.
+    pub id: Option<String>,
+    /// The `name` of the triggered element.
+    pub name: Option<String>,
+}
+
+impl HxTriggerInfo {
+    /// Returns a single value identifying the triggered element.
+    ///
+    /// The `id` takes precedence over the `name`, which is only used when the
+    /// element has no `id`. Empty values are skipped.
+    pub fn identifier(&self) -> Option<&str> {
+        self.id
+            .as_deref()
+            .filter(|id| !id.is_empty())
+            .or_else(|| self.name.as_deref().filter(|name| !name.is_empty()))
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTriggerInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxTriggerInfo {
+            id: header_string(parts, HX_TRIGGER),
+            name: header_string(parts, HX_TRIGGER_NAME),
+        });
+    }
+}
+
+/// Reference to an element by its `id` and `name`.
+///
+/// Can be extracted from the `HX-Trigger` and `HX-Trigger-Name` headers, but is
+/// also meant to be stored or logged on its own. Its [`Display`](fmt::Display)
+/// implementation writes the `id` if present, otherwise the `name`, and nothing
+/// if neither is known.
+///
+/// This extractor will always return a value. If a header is not present, its
+/// field will be `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HxElementRef {
+    /// The `id` of the element.
+    pub id: Option<String>,
+    /// The `name` of the element.
+    pub name: Option<String>,
+}
+
+impl HxElementRef {
+    /// Creates reference from an `id` and a `name`.
+    pub fn new(id: Option<String>, name: Option<String>) -> Self {
+        Self { id, name }
+    }
+
+    /// Creates reference to the element with the given `id`.
+    pub fn from_id(id: impl Into<String>) -> Self {
+        Self::new(Some(id.into()), None)
+    }
+
+    /// Creates reference to the element with the given `name`.
+    pub fn from_name(name: impl Into<String>) -> Self {
+        Self::new(None, Some(name.into()))
+    }
+}
+
+impl fmt::Display for HxElementRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.id, &self.name) {
+            (Some(id), _) => f.write_str(id),
+            (None, Some(name)) => f.write_str(name),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl From<HxTriggerInfo> for HxElementRef {
+    fn from(HxTriggerInfo { id, name }: HxTriggerInfo) -> Self {
+        Self { id, name }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxElementRef
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxElementRef {
+            id: header_string(parts, HX_TRIGGER),
+            name: header_string(parts, HX_TRIGGER_NAME),
+        });
+    }
+}
+
+/// All htmx request headers.
+///
+/// Contains every request header whose name starts with `HX-`, which is useful
+/// for logging or debugging the htmx request surface in a single place.
+///
+/// This extractor will always return a value. If no htmx headers are present,
+/// the map will be empty.
+#[derive(Debug, Clone)]
+pub struct HxHeaders(pub HeaderMap);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxHeaders
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        // Header names are always stored lowercase.
+        let headers = parts
+            .headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with("hx-"))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+
+        return Ok(HxHeaders(headers));
+    }
+}
+
+/// The header read by [`HxClientVersion`] unless overridden with
+/// [`HxClientVersionHeader`].
+#[cfg(feature = "semver")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "semver")))]
+pub const HX_CLIENT_VERSION: &str = "HX-Client-Version";
+
+/// The htmx client version, sent through a custom request header.
+///
+/// htmx does not send its version by default, but applications can add it to
+/// every request, e.g. with `hx-headers`. The header defaults to
+/// `HX-Client-Version` and can be changed by inserting a
+/// [`HxClientVersionHeader`] into the request extensions.
+///
+/// This extractor will always return a value. If the header is not present, or
+/// the extractor fails to parse the version it will return `None`.
+#[cfg(feature = "semver")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "semver")))]
+#[derive(Debug, Clone)]
+pub struct HxClientVersion(pub Option<semver::Version>);
+
+/// Overrides the header read by [`HxClientVersion`] when present in the
+/// request extensions.
+#[cfg(feature = "semver")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "semver")))]
+#[derive(Debug, Clone, Copy)]
+pub struct HxClientVersionHeader(pub &'static str);
+
+#[cfg(feature = "semver")]
+#[async_trait]
+impl<S> FromRequestParts<S> for HxClientVersion
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .extensions
+            .get::<HxClientVersionHeader>()
+            .map_or(HX_CLIENT_VERSION, |header| header.0);
+
+        if let Some(version) = parts.headers.get(header) {
+            let version = version
+                .to_str()
+                .ok()
+                .and_then(|version| version.parse::<semver::Version>().ok());
+
+            return Ok(HxClientVersion(version));
+        }
+
+        return Ok(HxClientVersion(None));
+    }
+}
+
+/// The headers read by [`HxCsrfToken`] unless overridden with
+/// [`HxCsrfTokenHeader`], in order of precedence.
+pub const CSRF_TOKEN_HEADERS: &[&str] = &["X-CSRF-Token", "HX-CSRF-Token"];
+
+/// A CSRF token, sent through a custom request header.
+///
+/// Applications usually add the token to every request with `hx-headers`, e.g.
+/// `hx-headers='{"X-CSRF-Token": "..."}'` on the `<body>`. The token is read
+/// from `X-CSRF-Token`, falling back to `HX-CSRF-Token`, and the header can be
+/// changed by inserting a [`HxCsrfTokenHeader`] into the request extensions.
+///
+/// The token is only extracted, comparing it against the expected value is up
+/// to the application. Use [`HxCsrfTokenRequired`] to reject requests without
+/// a token.
+///
+/// This extractor will always return a value. If the header is not present, it
+/// will return `None`.
+#[derive(Debug, Clone)]
+pub struct HxCsrfToken(pub Option<String>);
+
+/// Overrides the header read by [`HxCsrfToken`] and [`HxCsrfTokenRequired`]
+/// when present in the request extensions.
+#[derive(Debug, Clone, Copy)]
+pub struct HxCsrfTokenHeader(pub &'static str);
+
+fn csrf_token(parts: &Parts) -> Result<String, HxHeaderRejection> {
+    let headers = match parts.extensions.get::<HxCsrfTokenHeader>() {
+        Some(header) => std::slice::from_ref(&header.0),
+        None => CSRF_TOKEN_HEADERS,
+    };
+
+    let Some((&name, value)) = headers
+        .iter()
+        .find_map(|name| parts.headers.get(*name).map(|value| (name, value)))
+    else {
+        return Err(HxHeaderRejection::new(
+            headers[0],
+            HxHeaderRejectionReason::Missing,
+        ));
+    };
+
+    match value.to_str() {
+        Ok(token) => Ok(token.to_owned()),
+        Err(_) => Err(HxHeaderRejection::new(
+            name,
+            HxHeaderRejectionReason::Invalid,
+        )),
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxCsrfToken
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxCsrfToken(csrf_token(parts).ok()));
+    }
+}
+
+/// A CSRF token, rejecting requests without one.
+///
+/// Works like [`HxCsrfToken`], but rejects the request with an
+/// [`HxHeaderRejection`] if the header is missing or is not visible ASCII.
+#[derive(Debug, Clone)]
+pub struct HxCsrfTokenRequired(pub String);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxCsrfTokenRequired
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxCsrfTokenRequired(csrf_token(parts)?));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+
+    use super::*;
+
+    fn parts(headers: &[(&str, &str)]) -> Parts {
+        let mut req = Request::builder();
+        for (name, value) in headers {
+            req = req.header(*name, *value);
+        }
+
+        req.body(()).unwrap().into_parts().0
+    }
+
+    #[test]
+    fn test_is_boosted_history_nav() {
+        assert!(!is_boosted_history_nav(&parts(&[])));
+        assert!(!is_boosted_history_nav(&parts(&[(HX_BOOSTED, "true")])));
+        assert!(!is_boosted_history_nav(&parts(&[(
+            HX_HISTORY_RESTORE_REQUEST,
+            "true"
+        )])));
+        assert!(is_boosted_history_nav(&parts(&[
+            (HX_BOOSTED, "true"),
+            (HX_HISTORY_RESTORE_REQUEST, "true"),
+        ])));
+    }
+
+    #[tokio::test]
+    async fn test_hx_history_restore_request() {
+        async fn render(parts: &mut Parts) -> &'static str {
+            let restore = HxHistoryRestoreRequest::from_request_parts(parts, &())
+                .await
+                .unwrap();
+
+            if restore.is_restore() {
+                "cached"
+            } else {
+                "partial"
+            }
+        }
+
+        assert_eq!(render(&mut parts(&[])).await, "partial");
+        assert_eq!(
+            render(&mut parts(&[(HX_HISTORY_RESTORE_REQUEST, "true")])).await,
+            "cached"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hx_trigger_info_identifier() {
+        async fn identifier(headers: &[(&str, &str)]) -> Option<String> {
+            let info = HxTriggerInfo::from_request_parts(&mut parts(headers), &())
+                .await
+                .unwrap();
+            info.identifier().map(str::to_owned)
+        }
+
+        assert_eq!(identifier(&[]).await, None);
+        assert_eq!(
+            identifier(&[(HX_TRIGGER, "save")]).await.as_deref(),
+            Some("save")
+        );
+        assert_eq!(
+            identifier(&[(HX_TRIGGER_NAME, "email")]).await.as_deref(),
+            Some("email")
+        );
+        assert_eq!(
+            identifier(&[(HX_TRIGGER, "save"), (HX_TRIGGER_NAME, "email")])
+                .await
+                .as_deref(),
+            Some("save")
+        );
+        assert_eq!(
+            identifier(&[(HX_TRIGGER, ""), (HX_TRIGGER_NAME, "email")])
+                .await
+                .as_deref(),
+            Some("email")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hx_target_info() {
+        async fn info(headers: &[(&str, &str)]) -> HxTargetInfo {
+            HxTargetInfo::from_request_parts(&mut parts(headers), &())
+                .await
+                .unwrap()
+        }
+
+        let present = info(&[(HX_REQUEST, "true"), (HX_TARGET, "main")]).await;
+        assert_eq!(present.id(), Some("main"));
+        assert!(present.resolved());
+        assert!(!present.unresolved());
+
+        let absent = info(&[(HX_REQUEST, "true")]).await;
+        assert_eq!(absent.id(), None);
+        assert!(!absent.resolved());
+        assert!(absent.unresolved());
+
+        let plain = info(&[]).await;
+        assert!(!plain.resolved());
+        assert!(!plain.unresolved());
+
+        let HxTarget(target) = HxTarget::from_request_parts(&mut parts(&[]), &())
+            .await
+            .unwrap();
+        assert!(!HxTarget(target).resolved());
+        assert!(HxTarget(Some("main".into())).resolved());
+    }
+
+    #[tokio::test]
+    async fn test_hx_csrf_token() {
+        let HxCsrfToken(token) =
+            HxCsrfToken::from_request_parts(&mut parts(&[("X-CSRF-Token", "abc")]), &())
+                .await
+                .unwrap();
+        assert_eq!(token.as_deref(), Some("abc"));
+
+        let HxCsrfToken(token) =
+            HxCsrfToken::from_request_parts(&mut parts(&[("HX-CSRF-Token", "def")]), &())
+                .await
+                .unwrap();
+        assert_eq!(token.as_deref(), Some("def"));
+
+        let HxCsrfToken(token) = HxCsrfToken::from_request_parts(&mut parts(&[]), &())
+            .await
+            .unwrap();
+        assert!(token.is_none());
+
+        let rejection = HxCsrfTokenRequired::from_request_parts(&mut parts(&[]), &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), "X-CSRF-Token");
+        assert_eq!(rejection.reason(), HxHeaderRejectionReason::Missing);
+
+        let mut custom = parts(&[("X-CSRF-Token", "abc"), ("X-XSRF-Token", "xyz")]);
+        custom.extensions.insert(HxCsrfTokenHeader("X-XSRF-Token"));
+        let HxCsrfTokenRequired(token) = HxCsrfTokenRequired::from_request_parts(&mut custom, &())
+            .await
+            .unwrap();
+        assert_eq!(token, "xyz");
+
+        let mut custom = parts(&[("X-CSRF-Token", "abc")]);
+        custom.extensions.insert(HxCsrfTokenHeader("X-XSRF-Token"));
+        let rejection = HxCsrfTokenRequired::from_request_parts(&mut custom, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), "X-XSRF-Token");
+    }
+
+    #[tokio::test]
+    async fn test_hx_trigger_all() {
+        let HxTriggerAll(triggers) = HxTriggerAll::from_request_parts(
+            &mut parts(&[(HX_TRIGGER, "save"), (HX_TRIGGER, "form")]),
+            &(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(triggers, ["save", "form"]);
+
+        let HxTriggerAll(triggers) = HxTriggerAll::from_request_parts(&mut parts(&[]), &())
+            .await
+            .unwrap();
+        assert!(triggers.is_empty());
+    }
+
+    #[test]
+    fn test_hx_trigger_name_base_name() {
+        let name = HxTriggerName(Some("tags[]".into()));
+        assert_eq!(name.base_name(), Some("tags"));
+        assert!(name.is_array());
+
+        let name = HxTriggerName(Some("email".into()));
+        assert_eq!(name.base_name(), Some("email"));
+        assert!(!name.is_array());
+
+        let name = HxTriggerName(None);
+        assert_eq!(name.base_name(), None);
+        assert!(!name.is_array());
+    }
+
+    #[tokio::test]
+    async fn test_hx_method_hint() {
+        async fn hint(method: http::Method, htmx: bool) -> HxMethodHint {
+            let mut req = Request::builder().method(method);
+            if htmx {
+                req = req.header(HX_REQUEST, "true");
+            }
+
+            let mut parts = req.body(()).unwrap().into_parts().0;
+            HxMethodHint::from_request_parts(&mut parts, &())
+                .await
+                .unwrap()
+        }
+
+        assert_eq!(hint(http::Method::GET, true).await, HxMethodHint::HtmxGet);
+        assert_eq!(
+            hint(http::Method::POST, true).await,
+            HxMethodHint::HtmxSubmit(http::Method::POST)
+        );
+        assert_eq!(
+            hint(http::Method::GET, false).await,
+            HxMethodHint::Plain(http::Method::GET)
+        );
+
+        let post = hint(http::Method::POST, false).await;
+        assert_eq!(post, HxMethodHint::Plain(http::Method::POST));
+        assert!(!post.is_htmx());
+        assert!(hint(http::Method::DELETE, true).await.is_htmx());
+    }
+
+    #[tokio::test]
+    async fn test_hx_element_ref() {
+        assert_eq!(HxElementRef::from_id("save").to_string(), "save");
+        assert_eq!(HxElementRef::from_name("email").to_string(), "email");
+        assert_eq!(HxElementRef::default().to_string(), "");
+        assert_eq!(
+            HxElementRef::new(Some("save".into()), Some("email".into())).to_string(),
+            "save"
+        );
+
+        let element = HxElementRef::from_request_parts(
+            &mut parts(&[(HX_TRIGGER, "save"), (HX_TRIGGER_NAME, "email")]),
+            &(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            element,
+            HxElementRef::new(Some("save".into()), Some("email".into()))
+        );
+
+        let info = HxTriggerInfo {
+            id: None,
+            name: Some("email".into()),
+        };
+        assert_eq!(HxElementRef::from(info), HxElementRef::from_name("email"));
+    }
+
+    #[tokio::test]
+    async fn test_hx_headers() {
+        let mut parts = parts(&[
+            (HX_REQUEST, "true"),
+            (HX_TARGET, "main"),
+            ("content-type", "text/html"),
+            ("x-hx-fake", "1"),
+        ]);
+
+        let HxHeaders(headers) = HxHeaders::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get(HX_REQUEST).unwrap(), "true");
+        assert_eq!(headers.get(HX_TARGET).unwrap(), "main");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "semver")]
+    async fn test_hx_client_version() {
+        let mut valid = parts(&[(HX_CLIENT_VERSION, "1.9.10")]);
+        let HxClientVersion(version) = HxClientVersion::from_request_parts(&mut valid, &())
+            .await
+            .unwrap();
+        assert_eq!(version, Some(semver::Version::new(1, 9, 10)));
+
+        let mut invalid = parts(&[(HX_CLIENT_VERSION, "latest")]);
+        let HxClientVersion(version) = HxClientVersion::from_request_parts(&mut invalid, &())
+            .await
+            .unwrap();
+        assert_eq!(version, None);
+
+        let mut absent = parts(&[]);
+        let HxClientVersion(version) = HxClientVersion::from_request_parts(&mut absent, &())
+            .await
+            .unwrap();
+        assert_eq!(version, None);
+
+        let mut custom = parts(&[("X-Htmx-Version", "2.0.0")]);
+        custom
+            .extensions
+            .insert(HxClientVersionHeader("X-Htmx-Version"));
+        let HxClientVersion(version) = HxClientVersion::from_request_parts(&mut custom, &())
+            .await
+            .unwrap();
+        assert_eq!(version, Some(semver::Version::new(2, 0, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_hx_trigger_name_of() {
+        #[derive(Debug, PartialEq)]
+        enum Field {
+            Email,
+            Password,
+        }
+
+        impl FromStr for Field {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "email" => Ok(Field::Email),
+                    "password" => Ok(Field::Password),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        let mut present = parts(&[(HX_TRIGGER_NAME, "email")]);
+        let HxTriggerNameOf(field) =
+            HxTriggerNameOf::<Field>::from_request_parts(&mut present, &())
+                .await
+                .unwrap();
+        assert_eq!(field, Some(Field::Email));
+
+        let mut absent = parts(&[]);
+        let HxTriggerNameOf(field) = HxTriggerNameOf::<Field>::from_request_parts(&mut absent, &())
+            .await
+            .unwrap();
+        assert_eq!(field, None);
+
+        let mut unknown = parts(&[(HX_TRIGGER_NAME, "username")]);
+        let HxTriggerNameOf(field) =
+            HxTriggerNameOf::<Field>::from_request_parts(&mut unknown, &())
+                .await
+                .unwrap();
+        assert_eq!(field, None);
+    }
+
+    #[tokio::test]
+    async fn test_hx_prompt_bounded() {
+        let mut within = parts(&[(HX_PROMPT, "hello")]);
+        let HxPromptBounded(prompt) = HxPromptBounded::<5>::from_request_parts(&mut within, &())
+            .await
+            .unwrap();
+        assert_eq!(prompt.as_deref(), Some("hello"));
+
+        let mut over = parts(&[(HX_PROMPT, "hello!")]);
+        let rejection = HxPromptBounded::<5>::from_request_parts(&mut over, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_PROMPT);
+        assert_eq!(
+            rejection.reason(),
+            HxHeaderRejectionReason::TooLong { max: 5 }
+        );
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let mut invalid = Request::builder()
+            .header(
+                HX_PROMPT,
+                http::HeaderValue::from_bytes(b"caf\xe9").unwrap(),
+            )
+            .body(())
+            .unwrap()
+            .into_parts()
+            .0;
+        let rejection = HxPromptBounded::<5>::from_request_parts(&mut invalid, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.reason(), HxHeaderRejectionReason::Invalid);
+
+        let mut missing = parts(&[]);
+        let HxPromptBounded(prompt) = HxPromptBounded::<5>::from_request_parts(&mut missing, &())
+            .await
+            .unwrap();
+        assert_eq!(prompt, None);
+    }
+
+    #[tokio::test]
+    async fn test_hx_header_rejection() {
+        let cases = [
+            (
+                HxHeaderRejectionReason::Missing,
+                "Missing `HX-Prompt` header",
+            ),
+            (
+                HxHeaderRejectionReason::Invalid,
+                "Invalid `HX-Prompt` header",
+            ),
+            (
+                HxHeaderRejectionReason::TooLong { max: 5 },
+                "`HX-Prompt` header exceeds the maximum length of 5 bytes",
+            ),
+        ];
+
+        for (reason, message) in cases {
+            let res = HxHeaderRejection::new(HX_PROMPT, reason).into_response();
+            assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+            let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert_eq!(body, message);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hx_boosted_navigation() {
+        let mut direct = parts(&[]);
+        let nav = HxBoostedNavigation::from_request_parts(&mut direct, &())
+            .await
+            .unwrap();
+        assert!(!nav.boosted);
+        assert!(nav.current_url.is_none());
+        assert!(nav.should_render_full_page());
+
+        let mut boosted = parts(&[
+            (HX_BOOSTED, "true"),
+            (HX_CURRENT_URL, "https://example.com/users"),
+        ]);
+        let nav = HxBoostedNavigation::from_request_parts(&mut boosted, &())
+            .await
+            .unwrap();
+        assert!(nav.boosted);
+        assert_eq!(nav.current_url.as_ref().unwrap().path(), "/users");
+        assert!(!nav.should_render_full_page());
+
+        let mut restore = parts(&[(HX_BOOSTED, "true"), (HX_HISTORY_RESTORE_REQUEST, "true")]);
+        let nav = HxBoostedNavigation::from_request_parts(&mut restore, &())
+            .await
+            .unwrap();
+        assert!(nav.restore);
+        assert!(nav.should_render_full_page());
+    }
+
+    #[tokio::test]
+    async fn test_hx_request_raw() {
+        let mut present = parts(&[(HX_REQUEST, "v2")]);
+        let HxRequestRaw(raw) = HxRequestRaw::from_request_parts(&mut present, &())
+            .await
+            .unwrap();
+        assert_eq!(raw.as_deref(), Some("v2"));
+
+        let mut absent = parts(&[]);
+        let HxRequestRaw(raw) = HxRequestRaw::from_request_parts(&mut absent, &())
+            .await
+            .unwrap();
+        assert_eq!(raw, None);
+    }
+
+    #[test]
+    fn test_eq_ignore_case() {
+        assert!(HxTarget(Some("Main-Content".into())).eq_ignore_case("main-content"));
+        assert!(!HxTarget(Some("main".into())).eq_ignore_case("main-content"));
+        assert!(!HxTarget(None).eq_ignore_case("main"));
+
+        assert!(HxTrigger(Some("SaveButton".into())).eq_ignore_case("savebutton"));
+        assert!(!HxTrigger(None).eq_ignore_case("savebutton"));
+    }
+
+    #[tokio::test]
+    async fn test_hx_prompt_context() {
+        let mut full = parts(&[
+            (HX_PROMPT, "yes"),
+            (HX_TRIGGER, "delete-button"),
+            (HX_TRIGGER_NAME, "delete"),
+        ]);
+        let ctx = HxPromptContext::from_request_parts(&mut full, &())
+            .await
+            .unwrap();
+        assert_eq!(ctx.prompt(), Some("yes"));
+        assert_eq!(ctx.trigger(), Some("delete-button"));
+        assert_eq!(ctx.trigger_name(), Some("delete"));
+
+        let mut empty = parts(&[]);
+        let ctx = HxPromptContext::from_request_parts(&mut empty, &())
+            .await
+            .unwrap();
+        assert_eq!(ctx.prompt(), None);
+        assert_eq!(ctx.trigger(), None);
+        assert_eq!(ctx.trigger_name(), None);
+    }
+
+    #[test]
+    fn test_hx_rejection_adds_headers() {
+        use crate::response::HxResponseTrigger;
+
+        let rejection = HxRejection::new(
+            HxHeaderRejection::new(HX_PROMPT, HxHeaderRejectionReason::Missing),
+            HxResponseTrigger::normal(["show-error"]),
+        );
+
+        let res = rejection.into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(res.headers().get(HX_TRIGGER).unwrap(), "show-error");
+    }
+
+    #[test]
+    fn test_extract_current_url_garbage() {
+        for garbage in [
+            "",
+            " ",
+            "http://",
+            "://x",
+            "http://[::1",
+            "%",
+            "/%zz",
+            "\u{7f}",
+        ] {
+            let Ok(value) = http::HeaderValue::from_str(garbage) else {
+                continue;
+            };
+
+            let url = HxCurrentUrl(extract_current_url(&value));
+            url.normalized();
+        }
+
+        let value = http::HeaderValue::from_static("http://[::1");
+        assert!(extract_current_url(&value).is_none());
+    }
+
+    #[test]
+    fn test_hx_current_url_normalized() {
+        let url = |url: &str| HxCurrentUrl(Some(url.parse().unwrap()));
+
+        assert_eq!(
+            url("https://example.com/%7Euser/%61bc")
+                .normalized()
+                .unwrap(),
+            "https://example.com/~user/abc"
+        );
+        assert_eq!(
+            url("/search?q=a%2fb&tag=%e2%9c%93").normalized().unwrap(),
+            "/search?q=a%2Fb&tag=%E2%9C%93"
+        );
+        assert_eq!(
+            url("/plain/path?x=1").normalized().unwrap(),
+            "/plain/path?x=1"
+        );
+        assert_eq!(url("/bad%zzescape").normalized(), None);
+        assert_eq!(url("/truncated%4").normalized(), None);
+        assert_eq!(HxCurrentUrl(None).normalized(), None);
+    }
+}
@@ -0,0 +1,303 @@
+//! Strict variants of the header extractors.
+//!
+//! The extractors at the crate root never fail: a header that is present but
+//! malformed, e.g. a `HX-Current-Url` that is not a valid url or a value that
+//! is not visible ASCII, is treated the same as a missing header. The
+//! extractors in this module instead reject such requests with an
+//! [`HxHeaderRejection`], which responds with `400 Bad Request`.
+//!
+//! A missing header is still not an error, the field is `None` or `false` in
+//! that case, just like for the lenient extractors. htmx always sends `true`
+//! for the boolean headers, so only `true` and `false` are accepted for them.
+
+use async_trait::async_trait;
+use axum_core::extract::FromRequestParts;
+use http::{request::Parts, Uri};
+
+use crate::{
+    extract::{HxHeaderRejection, HxHeaderRejectionReason},
+    HX_BOOSTED, HX_CURRENT_URL, HX_HISTORY_RESTORE_REQUEST, HX_PROMPT, HX_REQUEST, HX_TARGET,
+    HX_TRIGGER, HX_TRIGGER_NAME,
+};
+
+fn invalid(name: &'static str) -> HxHeaderRejection {
+    HxHeaderRejection::new(name, HxHeaderRejectionReason::Invalid)
+}
+
+fn strict_string(parts: &Parts, name: &'static str) -> Result<Option<String>, HxHeaderRejection> {
+    match parts.headers.get(name) {
+        Some(value) => match value.to_str() {
+            Ok(value) => Ok(Some(value.to_owned())),
+            Err(_) => Err(invalid(name)),
+        },
+        None => Ok(None),
+    }
+}
+
+fn strict_bool(parts: &Parts, name: &'static str) -> Result<bool, HxHeaderRejection> {
+    match parts.headers.get(name) {
+        Some(value) if value == "true" => Ok(true),
+        Some(value) if value == "false" => Ok(false),
+        Some(_) => Err(invalid(name)),
+        None => Ok(false),
+    }
+}
+
+/// The `HX-Boosted` header, rejecting values other than `true` and `false`.
+///
+/// See [`crate::extract::HxBoosted`] for the lenient variant.
+#[derive(Debug, Clone, Copy)]
+pub struct HxBoosted(pub bool);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxBoosted
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxBoosted(strict_bool(parts, HX_BOOSTED)?));
+    }
+}
+
+/// The `HX-Current-Url` header, rejecting values that are not a valid url.
+///
+/// See [`crate::extract::HxCurrentUrl`] for the lenient variant.
+#[derive(Debug, Clone)]
+pub struct HxCurrentUrl(pub Option<Uri>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxCurrentUrl
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(HX_CURRENT_URL) else {
+            return Ok(HxCurrentUrl(None));
+        };
+
+        match crate::extract::extract_current_url(value) {
+            Some(url) => return Ok(HxCurrentUrl(Some(url))),
+            None => return Err(invalid(HX_CURRENT_URL)),
+        }
+    }
+}
+
+/// The `HX-History-Restore-Request` header, rejecting values other than `true`
+/// and `false`.
+///
+/// See [`crate::extract::HxHistoryRestoreRequest`] for the lenient variant.
+#[derive(Debug, Clone, Copy)]
+pub struct HxHistoryRestoreRequest(pub bool);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxHistoryRestoreRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxHistoryRestoreRequest(strict_bool(
+            parts,
+            HX_HISTORY_RESTORE_REQUEST,
+        )?));
+    }
+}
+
+/// The `HX-Prompt` header, rejecting values that are not visible ASCII.
+///
+/// See [`crate::extract::HxPrompt`] for the lenient variant.
+#[derive(Debug, Clone)]
+pub struct HxPrompt(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxPrompt
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxPrompt(strict_string(parts, HX_PROMPT)?));
+    }
+}
+
+/// The `HX-Request` header, rejecting values other than `true` and `false`.
+///
+/// See [`crate::extract::HxRequest`] for the lenient variant.
+#[derive(Debug, Clone, Copy)]
+pub struct HxRequest(pub bool);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxRequest(strict_bool(parts, HX_REQUEST)?));
+    }
+}
+
+/// The `HX-Target` header, rejecting values that are not visible ASCII.
+///
+/// See [`crate::extract::HxTarget`] for the lenient variant.
+#[derive(Debug, Clone)]
+pub struct HxTarget(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTarget
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxTarget(strict_string(parts, HX_TARGET)?));
+    }
+}
+
+/// The `HX-Trigger-Name` header, rejecting values that are not visible ASCII.
+///
+/// See [`crate::extract::HxTriggerName`] for the lenient variant.
+#[derive(Debug, Clone)]
+pub struct HxTriggerName(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTriggerName
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxTriggerName(strict_string(parts, HX_TRIGGER_NAME)?));
+    }
+}
+
+/// The `HX-Trigger` header, rejecting values that are not visible ASCII.
+///
+/// See [`crate::extract::HxTrigger`] for the lenient variant.
+#[derive(Debug, Clone)]
+pub struct HxTrigger(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HxTrigger
+where
+    S: Send + Sync,
+{
+    type Rejection = HxHeaderRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        return Ok(HxTrigger(strict_string(parts, HX_TRIGGER)?));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderValue, Request};
+
+    use super::*;
+
+    fn parts(name: &str, value: HeaderValue) -> Parts {
+        let req = Request::builder().header(name, value).body(()).unwrap();
+        req.into_parts().0
+    }
+
+    fn empty() -> Parts {
+        Request::new(()).into_parts().0
+    }
+
+    #[tokio::test]
+    async fn test_strict_current_url() {
+        let mut valid = parts(HX_CURRENT_URL, HeaderValue::from_static("/foo"));
+        let HxCurrentUrl(url) = HxCurrentUrl::from_request_parts(&mut valid, &())
+            .await
+            .unwrap();
+        assert_eq!(url.unwrap(), "/foo");
+
+        let HxCurrentUrl(url) = HxCurrentUrl::from_request_parts(&mut empty(), &())
+            .await
+            .unwrap();
+        assert!(url.is_none());
+
+        let mut invalid = parts(HX_CURRENT_URL, HeaderValue::from_static("http://[::1"));
+        let rejection = HxCurrentUrl::from_request_parts(&mut invalid, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_CURRENT_URL);
+        assert_eq!(rejection.reason(), HxHeaderRejectionReason::Invalid);
+
+        // The lenient extractor treats the same value as missing.
+        let crate::extract::HxCurrentUrl(url) =
+            crate::extract::HxCurrentUrl::from_request_parts(&mut invalid, &())
+                .await
+                .unwrap();
+        assert!(url.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_strict_string() {
+        let opaque = HeaderValue::from_bytes(b"caf\xe9").unwrap();
+
+        let HxPrompt(prompt) =
+            HxPrompt::from_request_parts(&mut parts(HX_PROMPT, "yes".parse().unwrap()), &())
+                .await
+                .unwrap();
+        assert_eq!(prompt.as_deref(), Some("yes"));
+
+        let rejection = HxPrompt::from_request_parts(&mut parts(HX_PROMPT, opaque.clone()), &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_PROMPT);
+
+        let rejection = HxTarget::from_request_parts(&mut parts(HX_TARGET, opaque.clone()), &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_TARGET);
+
+        let rejection = HxTrigger::from_request_parts(&mut parts(HX_TRIGGER, opaque.clone()), &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_TRIGGER);
+
+        let rejection = HxTriggerName::from_request_parts(&mut parts(HX_TRIGGER_NAME, opaque), &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.header(), HX_TRIGGER_NAME);
+    }
+
+    #[tokio::test]
+    async fn test_strict_bool() {
+        let HxRequest(request) =
+            HxRequest::from_request_parts(&mut parts(HX_REQUEST, "true".parse().unwrap()), &())
+                .await
+                .unwrap();
+        assert!(request);
+
+        let HxBoosted(boosted) = HxBoosted::from_request_parts(&mut empty(), &())
+            .await
+            .unwrap();
+        assert!(!boosted);
+
+        let HxHistoryRestoreRequest(restore) = HxHistoryRestoreRequest::from_request_parts(
+            &mut parts(HX_HISTORY_RESTORE_REQUEST, "false".parse().unwrap()),
+            &(),
+        )
+        .await
+        .unwrap();
+        assert!(!restore);
+
+        let rejection =
+            HxBoosted::from_request_parts(&mut parts(HX_BOOSTED, "yes".parse().unwrap()), &())
+                .await
+                .unwrap_err();
+        assert_eq!(rejection.header(), HX_BOOSTED);
+        assert_eq!(rejection.reason(), HxHeaderRejectionReason::Invalid);
+    }
+}
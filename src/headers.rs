@@ -74,3 +74,48 @@ pub const HX_TRIGGER_AFTER_SETTLE: &str = "HX-Trigger-After-Settle";
 ///
 /// See <https://htmx.org/headers/hx-trigger/> for more information.
 pub const HX_TRIGGER_AFTER_SWAP: &str = "HX-Trigger-After-Swap";
+
+/// All request headers sent by htmx.
+///
+/// Useful for allowlists or for stripping htmx headers before forwarding a
+/// request.
+pub const REQUEST_HEADERS: &[&str] = &[
+    HX_BOOSTED,
+    HX_CURRENT_URL,
+    HX_HISTORY_RESTORE_REQUEST,
+    HX_PROMPT,
+    HX_REQUEST,
+    HX_TARGET,
+    HX_TRIGGER,
+    HX_TRIGGER_NAME,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_headers() {
+        // Request headers listed at https://htmx.org/reference/#request_headers.
+        let mut documented = [
+            "HX-Boosted",
+            "HX-Current-URL",
+            "HX-History-Restore-Request",
+            "HX-Prompt",
+            "HX-Request",
+            "HX-Target",
+            "HX-Trigger-Name",
+            "HX-Trigger",
+        ]
+        .map(str::to_ascii_lowercase);
+        documented.sort();
+
+        let mut headers = REQUEST_HEADERS
+            .iter()
+            .map(|name| name.to_ascii_lowercase())
+            .collect::<Vec<_>>();
+        headers.sort();
+
+        assert_eq!(headers, documented);
+    }
+}
@@ -7,17 +7,39 @@ pub use error::*;
 
 pub mod extract;
 
-/// Axum middleware. Currently only includes guards to protect partial content.
+/// Axum middleware. Includes guards to protect partial content and rate
+/// limiting per triggering element.
 #[cfg(feature = "middleware")]
 #[cfg_attr(feature = "unstable", doc(cfg(feature = "middleware")))]
 pub mod middleware {
     mod guard;
     #[doc(inline)]
     pub use guard::*;
+
+    mod rate_limit;
+    #[doc(inline)]
+    pub use rate_limit::*;
 }
 
 pub mod headers;
 pub mod response;
 
+#[cfg(feature = "axum-test")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "axum-test")))]
+pub mod testing;
+
 #[doc(inline)]
 pub use headers::*;
+
+/// Entry points for the fuzz targets in `fuzz/`.
+#[cfg(fuzzing)]
+#[doc(hidden)]
+pub mod fuzzing {
+    /// Parses `data` as an `HX-Current-Url` header and normalizes the result.
+    pub fn current_url(data: &[u8]) -> Option<http::Uri> {
+        let value = http::HeaderValue::from_bytes(data).ok()?;
+        let url = crate::extract::extract_current_url(&value)?;
+
+        crate::extract::HxCurrentUrl(Some(url)).normalized()
+    }
+}
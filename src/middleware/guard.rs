@@ -3,38 +3,155 @@
 use std::{
     fmt,
     future::Future,
+    mem,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use futures_core::ready;
-use http::{header::LOCATION, response::Response, Request, StatusCode};
+use http::{
+    header::LOCATION, request::Parts, response::Response, HeaderName, HeaderValue, Request,
+    StatusCode,
+};
 use pin_project_lite::pin_project;
 use tower::{Layer, Service};
 
-use crate::HX_REQUEST;
+use crate::{HX_REDIRECT, HX_REQUEST};
+
+type Predicate = Arc<dyn Fn(&Parts) -> bool + Send + Sync>;
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type AsyncPredicate = Arc<dyn Fn(&Parts) -> BoxFuture<bool> + Send + Sync>;
 
 /// Checks if the request contains the `HX-Request` header, redirecting to the
 /// given location if not.
 ///
 /// This can be useful for preventing users from accidently ending up on a route
 /// which would otherwise return only partial HTML data.
-#[derive(Debug, Clone)]
+///
+/// Additional requirements can be added with
+/// [`HxRequestGuardLayer::with_predicate`] and
+/// [`HxRequestGuardLayer::with_async_predicate`]. A rejected request that does carry
+/// the `HX-Request` header is redirected with the `HX-Redirect` header instead
+/// of a `303 See Other`, since htmx would otherwise follow the redirect and swap
+/// the new page into the target. This can be changed with
+/// [`HxRequestGuardLayer::hx_redirect`].
+///
+/// Requests matching a rule added with [`HxRequestGuardLayer::allow_if_header`]
+/// skip the guard entirely.
+#[derive(Clone)]
 pub struct HxRequestGuardLayer<'a> {
     redirect_to: &'a str,
+    predicate: Option<Predicate>,
+    async_predicate: Option<AsyncPredicate>,
+    hx_redirect: bool,
+    allow_headers: Arc<Vec<(HeaderName, HeaderValue)>>,
 }
 
 impl<'a> HxRequestGuardLayer<'a> {
     #[inline]
     pub fn new(redirect_to: &'a str) -> Self {
-        Self { redirect_to }
+        Self {
+            redirect_to,
+            predicate: None,
+            async_predicate: None,
+            hx_redirect: true,
+            allow_headers: Arc::default(),
+        }
+    }
+
+    /// Additionally requires requests to satisfy `predicate`, which is only
+    /// checked for requests with the `HX-Request` header.
+    pub fn with_predicate<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&Parts) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Additionally requires requests to satisfy an asynchronous `predicate`,
+    /// e.g. one that looks up a session.
+    ///
+    /// The predicate only receives the request parts, so the returned future
+    /// has to own everything it needs. It is only awaited for requests with the
+    /// `HX-Request` header that passed [`HxRequestGuardLayer::with_predicate`].
+    ///
+    /// ```
+    /// use axum_htmx::middleware::HxRequestGuardLayer;
+    ///
+    /// async fn is_logged_in(session: Option<String>) -> bool {
+    ///     session.is_some()
+    /// }
+    ///
+    /// let layer = HxRequestGuardLayer::new("/login").with_async_predicate(|parts| {
+    ///     let session = parts
+    ///         .headers
+    ///         .get("x-session")
+    ///         .and_then(|value| value.to_str().ok())
+    ///         .map(str::to_owned);
+    ///
+    ///     is_logged_in(session)
+    /// });
+    /// ```
+    pub fn with_async_predicate<P, F>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&Parts) -> F + Send + Sync + 'static,
+        F: Future<Output = bool> + Send + 'static,
+    {
+        self.async_predicate = Some(Arc::new(move |parts| Box::pin(predicate(parts))));
+        self
+    }
+
+    /// Lets requests that carry the header `name` with `value` through, even
+    /// without the `HX-Request` header and without checking the predicates.
+    ///
+    /// This is meant for e.g. internal services or tests that fetch partial
+    /// routes directly. Multiple rules are combined, so a request matching any
+    /// of them is let through. Like `HX-Request`, the header can be set by any
+    /// client, so `value` should be a secret if the bypass must not be public.
+    ///
+    /// ```
+    /// use axum_htmx::middleware::HxRequestGuardLayer;
+    /// use http::{HeaderName, HeaderValue};
+    ///
+    /// let layer = HxRequestGuardLayer::default().allow_if_header(
+    ///     HeaderName::from_static("x-internal"),
+    ///     HeaderValue::from_static("reports"),
+    /// );
+    /// ```
+    pub fn allow_if_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        Arc::make_mut(&mut self.allow_headers).push((name, value));
+        self
+    }
+
+    /// Sets whether rejected htmx requests are redirected with the
+    /// `HX-Redirect` header rather than a `303 See Other`. Enabled by default.
+    pub fn hx_redirect(mut self, enabled: bool) -> Self {
+        self.hx_redirect = enabled;
+        self
     }
 }
 
 impl Default for HxRequestGuardLayer<'_> {
     #[inline]
     fn default() -> Self {
-        Self { redirect_to: "/" }
+        Self::new("/")
+    }
+}
+
+impl fmt::Debug for HxRequestGuardLayer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HxRequestGuardLayer")
+            .field("redirect_to", &self.redirect_to)
+            .field("predicate", &self.predicate.as_ref().map(|_| ".."))
+            .field(
+                "async_predicate",
+                &self.async_predicate.as_ref().map(|_| ".."),
+            )
+            .field("hx_redirect", &self.hx_redirect)
+            .field("allow_headers", &self.allow_headers)
+            .finish()
     }
 }
 
@@ -43,28 +160,80 @@ impl<'a, S> Layer<S> for HxRequestGuardLayer<'a> {
 
     #[inline]
     fn layer(&self, inner: S) -> Self::Service {
-        HxRequestGuard {
-            inner,
-            layer: self.clone(),
-        }
+        HxRequestGuard::new(inner, self.clone())
     }
 }
 
 /// Tower service that implementes redirecting to non-partial routes.
+///
+/// The guard is a plain tower service, so it can be tested without a server by
+/// calling it with
+/// [`ServiceExt::oneshot`](https://docs.rs/tower/latest/tower/trait.ServiceExt.html#method.oneshot):
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// use axum_htmx::{
+///     middleware::{HxRequestGuard, HxRequestGuardLayer},
+///     HX_REQUEST,
+/// };
+/// use http::{header::LOCATION, Request, Response, StatusCode};
+/// use tower::{service_fn, ServiceExt};
+///
+/// let partial = service_fn(|_: Request<()>| async {
+///     Ok::<_, std::convert::Infallible>(Response::new(String::from("partial")))
+/// });
+/// let guard = HxRequestGuard::new(partial, HxRequestGuardLayer::new("/home"));
+///
+/// let res = guard.clone().oneshot(Request::new(())).await.unwrap();
+/// assert_eq!(res.status(), StatusCode::SEE_OTHER);
+/// assert_eq!(res.headers()[LOCATION], "/home");
+///
+/// let req = Request::builder().header(HX_REQUEST, "true").body(()).unwrap();
+/// let res = guard.oneshot(req).await.unwrap();
+/// assert_eq!(res.body(), "partial");
+/// # });
+/// ```
 #[derive(Debug, Clone)]
 pub struct HxRequestGuard<'a, S> {
     inner: S,
     layer: HxRequestGuardLayer<'a>,
 }
 
+impl<'a, S> HxRequestGuard<'a, S> {
+    /// Creates new guard around `inner`, configured by `layer`.
+    ///
+    /// This is the same as calling [`Layer::layer`] on the `layer`.
+    pub fn new(inner: S, layer: HxRequestGuardLayer<'a>) -> Self {
+        Self { inner, layer }
+    }
+
+    /// Returns a reference to the inner service.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner service.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Consumes the guard, returning the inner service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
 impl<'a, S, T, U> Service<Request<T>> for HxRequestGuard<'a, S>
 where
-    S: Service<Request<T>, Response = Response<U>>,
+    S: Service<Request<T>, Response = Response<U>> + Clone,
     U: Default,
 {
     type Response = S::Response;
     type Error = S::Error;
-    type Future = private::ResponseFuture<'a, S::Future>;
+    type Future = private::ResponseFuture<'a, S, Request<T>>;
 
     fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         self.inner.poll_ready(cx)
@@ -73,10 +242,48 @@ where
     fn call(&mut self, req: Request<T>) -> Self::Future {
         // This will always contain a "true" value.
         let hx_request = req.headers().contains_key(HX_REQUEST);
-        let response_future = self.inner.call(req);
+        let bypass = self.layer.allow_headers.iter().any(|(name, value)| {
+            req.headers()
+                .get_all(name)
+                .iter()
+                .any(|header| header == value)
+        });
+
+        let (parts, body) = req.into_parts();
+        let allowed = bypass
+            || hx_request
+                && match &self.layer.predicate {
+                    Some(predicate) => predicate(&parts),
+                    None => true,
+                };
+        let predicate_future = match &self.layer.async_predicate {
+            Some(predicate) if allowed && !bypass => Some(predicate(&parts)),
+            _ => None,
+        };
+        let req = Request::from_parts(parts, body);
+
+        // The inner service is only called once the request is allowed.
+        let state = match predicate_future {
+            _ if !allowed => private::State::Redirect,
+            Some(future) => {
+                // `self.inner` was driven to readiness, so move it into the
+                // future and keep the fresh clone for the next call.
+                let clone = self.inner.clone();
+                let service = mem::replace(&mut self.inner, clone);
+
+                private::State::Predicate {
+                    future,
+                    service: Some(service),
+                    request: Some(req),
+                }
+            }
+            None => private::State::Inner {
+                future: self.inner.call(req),
+            },
+        };
 
         private::ResponseFuture {
-            response_future,
+            state,
             hx_request,
             layer: self.layer.clone(),
         }
@@ -87,37 +294,92 @@ mod private {
     use super::*;
 
     pin_project! {
-        pub struct ResponseFuture<'a, F> {
+        /// Future of [`HxRequestGuard`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if it is polled again after it has completed.
+        pub struct ResponseFuture<'a, S, R>
+        where
+            S: Service<R>,
+        {
             #[pin]
-            pub(super) response_future: F,
+            pub(super) state: State<S, R>,
             pub(super) hx_request: bool,
             pub(super) layer: HxRequestGuardLayer<'a>,
         }
     }
 
-    impl<'a, F, B, E> Future for ResponseFuture<'a, F>
+    pin_project! {
+        #[project = StateProj]
+        pub(super) enum State<S, R>
+        where
+            S: Service<R>,
+        {
+            Predicate {
+                future: BoxFuture<bool>,
+                service: Option<S>,
+                request: Option<R>,
+            },
+            Inner {
+                #[pin]
+                future: S::Future,
+            },
+            Redirect,
+            Done,
+        }
+    }
+
+    impl<'a, S, R, B> Future for ResponseFuture<'a, S, R>
     where
-        F: Future<Output = Result<Response<B>, E>>,
+        S: Service<R, Response = Response<B>>,
         B: Default,
     {
-        type Output = Result<Response<B>, E>;
+        type Output = Result<Response<B>, S::Error>;
 
         fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-            let this = self.project();
-            let response: Response<B> = ready!(this.response_future.poll(cx))?;
-
-            match *this.hx_request {
-                true => Poll::Ready(Ok(response)),
-                false => {
-                    let res = Response::builder()
-                        .status(StatusCode::SEE_OTHER)
-                        .header(LOCATION, this.layer.redirect_to)
-                        .body(B::default())
-                        .expect("failed to build response");
-
-                    Poll::Ready(Ok(res))
+            let mut this = self.project();
+
+            loop {
+                match this.state.as_mut().project() {
+                    StateProj::Predicate {
+                        future,
+                        service,
+                        request,
+                    } => {
+                        if ready!(future.as_mut().poll(cx)) {
+                            let mut service = service.take().expect("service taken twice");
+                            let request = request.take().expect("request taken twice");
+                            let future = service.call(request);
+                            this.state.set(State::Inner { future });
+                        } else {
+                            this.state.set(State::Redirect);
+                        }
+                    }
+                    StateProj::Inner { future } => {
+                        let response = ready!(future.poll(cx));
+                        this.state.set(State::Done);
+                        return Poll::Ready(response);
+                    }
+                    StateProj::Redirect => break,
+                    StateProj::Done => panic!("`HxRequestGuard` future polled after completion"),
                 }
             }
+
+            this.state.set(State::Done);
+
+            let res = if *this.hx_request && this.layer.hx_redirect {
+                Response::builder()
+                    .header(HX_REDIRECT, this.layer.redirect_to)
+                    .body(B::default())
+            } else {
+                Response::builder()
+                    .status(StatusCode::SEE_OTHER)
+                    .header(LOCATION, this.layer.redirect_to)
+                    .body(B::default())
+            };
+
+            Poll::Ready(Ok(res.expect("failed to build response")))
         }
     }
 }
@@ -132,3 +394,257 @@ impl fmt::Display for HxRequestGuardError {
 }
 
 impl std::error::Error for HxRequestGuardError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+
+    async fn call(layer: HxRequestGuardLayer<'static>, req: Request<()>) -> Response<String> {
+        let service = service_fn(|_: Request<()>| async {
+            Ok::<_, Infallible>(Response::new(String::from("partial")))
+        });
+
+        layer.layer(service).oneshot(req).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_default_redirect() {
+        let res = call(HxRequestGuardLayer::default(), Request::new(())).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/");
+        assert!(res.body().is_empty());
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .body(())
+            .unwrap();
+        let res = call(HxRequestGuardLayer::default(), req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.body(), "partial");
+    }
+
+    #[tokio::test]
+    async fn test_custom_redirect() {
+        let res = call(HxRequestGuardLayer::new("/home"), Request::new(())).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/home");
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .body(())
+            .unwrap();
+        let res = call(HxRequestGuardLayer::new("/home"), req).await;
+        assert_eq!(res.body(), "partial");
+        assert!(res.headers().get(LOCATION).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_htmx_request_failing_predicate_uses_hx_redirect() {
+        let layer = HxRequestGuardLayer::new("/login")
+            .with_predicate(|parts| parts.headers.contains_key("x-session"));
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(HX_REDIRECT).unwrap(), "/login");
+        assert!(res.headers().get(LOCATION).is_none());
+        assert!(res.body().is_empty());
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "1")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.body(), "partial");
+
+        let req = Request::builder().body(()).unwrap();
+        let res = call(layer, req).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/login");
+    }
+
+    #[tokio::test]
+    async fn test_hx_redirect_disabled_uses_see_other() {
+        let layer = HxRequestGuardLayer::new("/login")
+            .with_predicate(|_| false)
+            .hx_redirect(false);
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .body(())
+            .unwrap();
+        let res = call(layer, req).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/login");
+        assert!(res.headers().get(HX_REDIRECT).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_async_predicate() {
+        let layer = HxRequestGuardLayer::new("/login").with_async_predicate(|parts| {
+            let session = parts.headers.get("x-session").cloned();
+            async move { session.is_some_and(|value| value == "valid") }
+        });
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "valid")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.body(), "partial");
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "expired")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.headers().get(HX_REDIRECT).unwrap(), "/login");
+
+        let layer = layer.with_predicate(|_| false);
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "valid")
+            .body(())
+            .unwrap();
+        let res = call(layer, req).await;
+        assert_eq!(res.headers().get(HX_REDIRECT).unwrap(), "/login");
+    }
+
+    #[tokio::test]
+    async fn test_async_denied_request_skips_inner_service() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let layer = HxRequestGuardLayer::new("/login").with_async_predicate(|parts| {
+            let session = parts.headers.get("x-session").cloned();
+            async move { session.is_some_and(|value| value == "valid") }
+        });
+        let guard = layer.layer(counting_service(calls.clone()));
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "expired")
+            .body(())
+            .unwrap();
+        let res = guard.clone().oneshot(req).await.unwrap();
+        assert_eq!(res.headers().get(HX_REDIRECT).unwrap(), "/login");
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .header("x-session", "valid")
+            .body(())
+            .unwrap();
+        let res = guard.oneshot(req).await.unwrap();
+        assert_eq!(res.body(), "partial");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_allow_if_header() {
+        let layer = HxRequestGuardLayer::new("/login")
+            .with_predicate(|_| false)
+            .allow_if_header(
+                HeaderName::from_static("x-internal"),
+                HeaderValue::from_static("reports"),
+            )
+            .allow_if_header(
+                HeaderName::from_static("x-test"),
+                HeaderValue::from_static("1"),
+            );
+
+        let req = Request::builder()
+            .header("x-internal", "reports")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.body(), "partial");
+
+        let req = Request::builder().header("x-test", "1").body(()).unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.body(), "partial");
+
+        let req = Request::builder()
+            .header("x-internal", "other")
+            .body(())
+            .unwrap();
+        let res = call(layer.clone(), req).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+
+        let res = call(layer, Request::new(())).await;
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/login");
+    }
+
+    fn counting_service(
+        calls: Arc<AtomicUsize>,
+    ) -> impl Service<Request<()>, Response = Response<String>, Error = Infallible, Future = impl Send>
+           + Clone {
+        service_fn(move |_: Request<()>| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, Infallible>(Response::new(String::from("partial"))) }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_denied_request_skips_inner_service() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let layer = HxRequestGuardLayer::new("/login").with_predicate(|_| false);
+        let guard = layer.layer(counting_service(calls.clone()));
+
+        let req = Request::builder()
+            .header(HX_REQUEST, "true")
+            .body(())
+            .unwrap();
+        let res = guard.clone().oneshot(req).await.unwrap();
+        assert_eq!(res.headers().get(HX_REDIRECT).unwrap(), "/login");
+
+        let res = guard.oneshot(Request::new(())).await.unwrap();
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "polled after completion")]
+    fn test_poll_past_completion() {
+        let service = service_fn(|_: Request<()>| async {
+            Ok::<_, Infallible>(Response::new(String::from("partial")))
+        });
+        let mut guard = HxRequestGuardLayer::new("/login").layer(service);
+
+        let req = Request::builder().body(()).unwrap();
+        let mut future = std::pin::pin!(guard.call(req));
+        let mut cx = Context::from_waker(std::task::Waker::noop());
+
+        let Poll::Ready(Ok(res)) = future.as_mut().poll(&mut cx) else {
+            panic!("guard should complete immediately");
+        };
+        assert_eq!(res.status(), StatusCode::SEE_OTHER);
+
+        let _ = future.as_mut().poll(&mut cx);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Inner(u32);
+
+    #[test]
+    fn test_inner_service_access() {
+        let mut guard = HxRequestGuardLayer::default().layer(Inner(1));
+        assert_eq!(guard.get_ref(), &Inner(1));
+
+        guard.get_mut().0 = 2;
+        assert_eq!(guard.into_inner(), Inner(2));
+    }
+}
@@ -0,0 +1,382 @@
+//! Rate limiting keyed on the element that triggered the request.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use http::{request::Parts, response::Response, HeaderMap, Request, StatusCode};
+use pin_project_lite::pin_project;
+use tower::{Layer, Service};
+
+use crate::{HX_TRIGGER, HX_TRIGGER_NAME};
+
+type KeyFn = Arc<dyn Fn(&Parts) -> Option<String> + Send + Sync>;
+
+/// Limits how often each triggering element can send a request.
+///
+/// Requests are keyed on the `HX-Trigger` header, falling back to
+/// `HX-Trigger-Name` for elements without an `id`. Every key gets a token
+/// bucket that holds up to `burst` requests and refills at `burst` requests per
+/// `period`. Requests from a key with an empty bucket are answered with
+/// `429 Too Many Requests` without calling the inner service. Requests without
+/// either header are not limited.
+///
+/// This is meant for taming elements that poll or trigger on every keystroke.
+/// Both headers are sent by the client, so it is not a replacement for rate
+/// limiting per client.
+///
+/// By default, all clients share the bucket of a triggering element, so one
+/// client can exhaust it for everyone else. Use
+/// [`HtmxTriggerRateLimitLayer::with_key`] to include the client in the key.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use axum_htmx::{middleware::HtmxTriggerRateLimitLayer, HX_TRIGGER};
+///
+/// // At most 5 requests per second from each triggering element.
+/// let layer = HtmxTriggerRateLimitLayer::new(5, Duration::from_secs(1));
+///
+/// // At most 5 requests per second from each triggering element of a session.
+/// let layer = HtmxTriggerRateLimitLayer::new(5, Duration::from_secs(1)).with_key(|parts| {
+///     let session = parts.headers.get("x-session")?.to_str().ok()?;
+///     let trigger = parts.headers.get(HX_TRIGGER)?.to_str().ok()?;
+///     Some(format!("{session}:{trigger}"))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct HtmxTriggerRateLimitLayer {
+    burst: u32,
+    period: Duration,
+    key: KeyFn,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl HtmxTriggerRateLimitLayer {
+    /// Creates new layer allowing `burst` requests per `period` for each
+    /// triggering element.
+    ///
+    /// Services created by the same layer, and its clones, share the buckets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero or `period` is zero.
+    pub fn new(burst: u32, period: Duration) -> Self {
+        assert!(burst > 0, "burst must be greater than zero");
+        assert!(!period.is_zero(), "period must be greater than zero");
+
+        Self {
+            burst,
+            period,
+            key: Arc::new(|parts| trigger_key(&parts.headers).map(str::to_owned)),
+            buckets: Arc::default(),
+        }
+    }
+
+    /// Keys the buckets on the value returned by `key` instead of the
+    /// triggering element, e.g. to combine it with a session or client
+    /// address. Requests for which `key` returns `None` are not limited.
+    pub fn with_key<K>(mut self, key: K) -> Self
+    where
+        K: Fn(&Parts) -> Option<String> + Send + Sync + 'static,
+    {
+        self.key = Arc::new(key);
+        self
+    }
+
+    fn try_acquire(&self, key: &str, now: Instant) -> bool {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(bucket) = buckets.get_mut(key) {
+            return bucket.try_acquire(now, self.burst, self.period);
+        }
+
+        // Buckets that have refilled completely behave like new ones, so they
+        // can be dropped to keep the map from growing with every key.
+        if buckets.len() >= PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| !bucket.is_full(now, self.burst, self.period));
+        }
+
+        let mut bucket = Bucket::new(self.burst, now);
+        let acquired = bucket.try_acquire(now, self.burst, self.period);
+        buckets.insert(key.to_owned(), bucket);
+
+        acquired
+    }
+}
+
+impl fmt::Debug for HtmxTriggerRateLimitLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HtmxTriggerRateLimitLayer")
+            .field("burst", &self.burst)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Layer<S> for HtmxTriggerRateLimitLayer {
+    type Service = HtmxTriggerRateLimit<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        HtmxTriggerRateLimit {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// Number of buckets after which fully refilled buckets are dropped.
+const PRUNE_THRESHOLD: usize = 1024;
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(burst: u32, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(burst),
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant, burst: u32, period: Duration) {
+        let elapsed = now.saturating_duration_since(self.updated);
+        let refilled = elapsed.as_secs_f64() / period.as_secs_f64() * f64::from(burst);
+
+        self.tokens = (self.tokens + refilled).min(f64::from(burst));
+        self.updated = now;
+    }
+
+    fn try_acquire(&mut self, now: Instant, burst: u32, period: Duration) -> bool {
+        self.refill(now, burst, period);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_full(&self, now: Instant, burst: u32, period: Duration) -> bool {
+        let mut bucket = self.clone();
+        bucket.refill(now, burst, period);
+        bucket.tokens >= f64::from(burst)
+    }
+}
+
+fn trigger_key(headers: &HeaderMap) -> Option<&str> {
+    [HX_TRIGGER, HX_TRIGGER_NAME].into_iter().find_map(|name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// Tower service that implements [`HtmxTriggerRateLimitLayer`].
+#[derive(Debug, Clone)]
+pub struct HtmxTriggerRateLimit<S> {
+    inner: S,
+    layer: HtmxTriggerRateLimitLayer,
+}
+
+impl<S> HtmxTriggerRateLimit<S> {
+    /// Returns a reference to the inner service.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner service.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Consumes the rate limit, returning the inner service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, T, U> Service<Request<T>> for HtmxTriggerRateLimit<S>
+where
+    S: Service<Request<T>, Response = Response<U>>,
+    U: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = private::ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<T>) -> Self::Future {
+        let (parts, body) = req.into_parts();
+        let allowed = match (self.layer.key)(&parts) {
+            Some(key) => self.layer.try_acquire(&key, Instant::now()),
+            None => true,
+        };
+        let req = Request::from_parts(parts, body);
+
+        if allowed {
+            private::ResponseFuture::Inner {
+                future: self.inner.call(req),
+            }
+        } else {
+            private::ResponseFuture::Limited
+        }
+    }
+}
+
+mod private {
+    use super::*;
+
+    pin_project! {
+        /// Future of [`HtmxTriggerRateLimit`].
+        #[project = ResponseFutureProj]
+        pub enum ResponseFuture<F> {
+            Inner {
+                #[pin]
+                future: F,
+            },
+            Limited,
+        }
+    }
+
+    impl<F, B, E> Future for ResponseFuture<F>
+    where
+        F: Future<Output = Result<Response<B>, E>>,
+        B: Default,
+    {
+        type Output = Result<Response<B>, E>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            match self.project() {
+                ResponseFutureProj::Inner { future } => future.poll(cx),
+                ResponseFutureProj::Limited => {
+                    let mut res = Response::new(B::default());
+                    *res.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+
+                    Poll::Ready(Ok(res))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+
+    async fn call(layer: &HtmxTriggerRateLimitLayer, headers: &[(&str, &str)]) -> StatusCode {
+        let service = service_fn(|_: Request<()>| async {
+            Ok::<_, Infallible>(Response::new(String::from("partial")))
+        });
+
+        let mut req = Request::builder();
+        for (name, value) in headers {
+            req = req.header(*name, *value);
+        }
+
+        let res = layer
+            .layer(service)
+            .oneshot(req.body(()).unwrap())
+            .await
+            .unwrap();
+        res.status()
+    }
+
+    #[tokio::test]
+    async fn test_rapid_requests_from_same_trigger() {
+        let layer = HtmxTriggerRateLimitLayer::new(2, Duration::from_secs(60));
+
+        assert_eq!(
+            call(&layer, &[(HX_TRIGGER, "search")]).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(&layer, &[(HX_TRIGGER, "search")]).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(&layer, &[(HX_TRIGGER, "search")]).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // Other triggers have their own bucket.
+        assert_eq!(call(&layer, &[(HX_TRIGGER, "poll")]).await, StatusCode::OK);
+        assert_eq!(
+            call(&layer, &[(HX_TRIGGER_NAME, "search")]).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // Requests without a trigger are not limited.
+        for _ in 0..5 {
+            assert_eq!(call(&layer, &[]).await, StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_key() {
+        let layer = HtmxTriggerRateLimitLayer::new(1, Duration::from_secs(60)).with_key(|parts| {
+            let session = parts.headers.get("x-session")?.to_str().ok()?;
+            let trigger = trigger_key(&parts.headers)?;
+            Some(format!("{session}:{trigger}"))
+        });
+
+        let alice = [("x-session", "alice"), (HX_TRIGGER, "search")];
+        let bob = [("x-session", "bob"), (HX_TRIGGER, "search")];
+
+        assert_eq!(call(&layer, &alice).await, StatusCode::OK);
+        assert_eq!(call(&layer, &alice).await, StatusCode::TOO_MANY_REQUESTS);
+
+        // Another session triggering the same element has its own bucket.
+        assert_eq!(call(&layer, &bob).await, StatusCode::OK);
+
+        // Requests without a key are not limited.
+        for _ in 0..3 {
+            assert_eq!(
+                call(&layer, &[(HX_TRIGGER, "search")]).await,
+                StatusCode::OK
+            );
+        }
+    }
+
+    #[test]
+    fn test_bucket_refill() {
+        let burst = 2;
+        let period = Duration::from_secs(1);
+        let start = Instant::now();
+        let mut bucket = Bucket::new(burst, start);
+
+        assert!(bucket.try_acquire(start, burst, period));
+        assert!(bucket.try_acquire(start, burst, period));
+        assert!(!bucket.try_acquire(start, burst, period));
+
+        let later = start + Duration::from_millis(500);
+        assert!(bucket.try_acquire(later, burst, period));
+        assert!(!bucket.try_acquire(later, burst, period));
+        assert!(!bucket.is_full(later, burst, period));
+
+        let much_later = later + Duration::from_secs(10);
+        assert!(bucket.is_full(much_later, burst, period));
+        assert!(bucket.try_acquire(much_later, burst, period));
+        assert!(bucket.try_acquire(much_later, burst, period));
+        assert!(!bucket.try_acquire(much_later, burst, period));
+    }
+}
@@ -1,12 +1,14 @@
 //! Axum responses for htmx response headers.
 
-use std::{convert::Infallible, str::FromStr};
+use std::{borrow::Cow, convert::Infallible, fmt, str::FromStr, time::Duration};
 
-use axum_core::response::{IntoResponseParts, ResponseParts};
-use http::{HeaderValue, Uri};
+use axum_core::response::{IntoResponse, IntoResponseParts, Response, ResponseParts};
+use http::{HeaderValue, StatusCode, Uri};
 
 use crate::{headers, HxError};
 
+mod command;
+pub use command::*;
 mod location;
 pub use location::*;
 mod trigger;
@@ -21,6 +23,11 @@ const HX_SWAP_AFTER_END: &str = "afterend";
 const HX_SWAP_DELETE: &str = "delete";
 const HX_SWAP_NONE: &str = "none";
 
+/// htmx's default for `htmx.config.defaultSwapDelay`.
+const DEFAULT_SWAP_DELAY: Duration = Duration::ZERO;
+/// htmx's default for `htmx.config.defaultSettleDelay`.
+const DEFAULT_SETTLE_DELAY: Duration = Duration::from_millis(20);
+
 /// The `HX-Push-Url` header.
 ///
 /// Pushes a new url into the history stack.
@@ -40,6 +47,7 @@ impl IntoResponseParts for HxPushUrl {
             headers::HX_PUSH_URL,
             HeaderValue::from_maybe_shared(self.0.to_string())?,
         );
+        AppliedHtmxHeaders::record(&mut res, headers::HX_PUSH_URL);
 
         Ok(res)
     }
@@ -59,6 +67,33 @@ impl<'a> TryFrom<&'a str> for HxPushUrl {
     }
 }
 
+/// Prevents htmx from adding a history entry for the response.
+///
+/// Sets `HX-Push-Url: false`, which overrides an `hx-push-url` attribute on the
+/// triggering element, so the url in the location bar stays the same and no
+/// entry is pushed onto the history stack. It does not disable htmx's history
+/// snapshot of the current page, which is controlled with the `hx-history`
+/// attribute on the client. `HX-Replace-Url: false` is not needed, since htmx
+/// only replaces the url if asked to.
+///
+/// See <https://htmx.org/headers/hx-push-url/> for more information.
+///
+/// This responder will never fail.
+#[derive(Debug, Copy, Clone)]
+pub struct HxNoHistory;
+
+impl IntoResponseParts for HxNoHistory {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        res.headers_mut()
+            .insert(headers::HX_PUSH_URL, HeaderValue::from_static("false"));
+        AppliedHtmxHeaders::record(&mut res, headers::HX_PUSH_URL);
+
+        Ok(res)
+    }
+}
+
 /// The `HX-Redirect` header.
 ///
 /// Can be used to do a client-side redirect to a new location.
@@ -76,11 +111,76 @@ impl IntoResponseParts for HxRedirect {
             headers::HX_REDIRECT,
             HeaderValue::from_maybe_shared(self.0.to_string())?,
         );
+        AppliedHtmxHeaders::record(&mut res, headers::HX_REDIRECT);
 
         Ok(res)
     }
 }
 
+/// Returns whether `uri` starts with a scheme such as `https:`.
+fn has_scheme(uri: &str) -> bool {
+    match uri.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
+
+impl HxRedirect {
+    /// Creates redirect to `uri`, rejecting urls that point to an origin other
+    /// than the one of `base`.
+    ///
+    /// This protects against open redirects when the target is influenced by
+    /// the user, such as a `next` query parameter. Absolute paths like
+    /// `/dashboard` are always allowed, with the exception of paths starting
+    /// with `//` or `/\`, which browsers treat as urls to another host.
+    /// Absolute urls are only allowed if their scheme and authority match the
+    /// ones of `base`.
+    ///
+    /// Relative paths that don't start with `/`, such as `dashboard` or
+    /// `../settings`, are not resolved against `base` and are rejected. So is
+    /// anything else, including a host without a scheme.
+    pub fn same_origin(uri: impl AsRef<str>, base: &Uri) -> Result<Self, HxError> {
+        let uri = uri.as_ref();
+        if !uri.starts_with('/') && !has_scheme(uri) {
+            return Err(HxError::InvalidRedirect("relative path"));
+        }
+
+        let uri = uri
+            .parse::<Uri>()
+            .map_err(|_| HxError::InvalidRedirect("invalid uri"))?;
+
+        match (uri.scheme(), uri.authority()) {
+            (None, None) => {
+                if uri.path().starts_with("//") || uri.path().starts_with("/\\") {
+                    return Err(HxError::InvalidRedirect("protocol-relative url"));
+                }
+            }
+            (Some(scheme), Some(authority)) => {
+                if base.scheme() != Some(scheme) || base.authority() != Some(authority) {
+                    return Err(HxError::InvalidRedirect("url to another origin"));
+                }
+            }
+            _ => return Err(HxError::InvalidRedirect("url to another origin")),
+        }
+
+        Ok(Self(uri))
+    }
+
+    /// Converts the redirect into a client-side navigation to the same
+    /// location.
+    ///
+    /// Useful together with [`HxLocation::try_into_redirect`] to pick the
+    /// header depending on the request, e.g. whether it is boosted.
+    pub fn into_location(self) -> HxLocation {
+        HxLocation::from_uri(self.0)
+    }
+}
+
 impl From<Uri> for HxRedirect {
     fn from(uri: Uri) -> Self {
         Self(uri)
@@ -99,6 +199,10 @@ impl<'a> TryFrom<&'a str> for HxRedirect {
 ///
 /// If set to `true` the client-side will do a full refresh of the page.
 ///
+/// Setting it to `false` does not emit the header, as htmx treats a missing
+/// header the same way. It does however remove an `HX-Refresh` header set by an
+/// earlier responder, so the last `HxRefresh` in a response wins.
+///
 /// This responder will never fail.
 #[derive(Debug, Copy, Clone)]
 pub struct HxRefresh(pub bool);
@@ -113,14 +217,14 @@ impl IntoResponseParts for HxRefresh {
     type Error = Infallible;
 
     fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
-        res.headers_mut().insert(
-            headers::HX_REFRESH,
-            if self.0 {
-                HeaderValue::from_static("true")
-            } else {
-                HeaderValue::from_static("false")
-            },
-        );
+        if self.0 {
+            res.headers_mut()
+                .insert(headers::HX_REFRESH, HeaderValue::from_static("true"));
+            AppliedHtmxHeaders::record(&mut res, headers::HX_REFRESH);
+        } else {
+            res.headers_mut().remove(headers::HX_REFRESH);
+            AppliedHtmxHeaders::forget(&mut res, headers::HX_REFRESH);
+        }
 
         Ok(res)
     }
@@ -145,6 +249,7 @@ impl IntoResponseParts for HxReplaceUrl {
             headers::HX_REPLACE_URL,
             HeaderValue::from_maybe_shared(self.0.to_string())?,
         );
+        AppliedHtmxHeaders::record(&mut res, headers::HX_REPLACE_URL);
 
         Ok(res)
     }
@@ -166,25 +271,419 @@ impl<'a> TryFrom<&'a str> for HxReplaceUrl {
 
 /// The `HX-Reswap` header.
 ///
-/// Allows you to specidy how the response will be swapped.
+/// Allows you to specidy how the response will be swapped. Additional `hx-swap`
+/// modifiers can be set with the builder methods.
 ///
-/// This responder will never fail.
-#[derive(Debug, Copy, Clone)]
-pub struct HxReswap(pub SwapOption);
+/// Will fail if a modifier is invalid or contains characters that are not
+/// visible ASCII (32-127). Timing, `scroll` and `show` modifiers are rejected
+/// for the `none` and `delete` styles, which don't swap in any content.
+/// Selectors of `scroll` and `show` can't contain whitespace, since htmx splits
+/// modifiers on it.
+///
+/// Combinations that are allowed but usually a mistake are not rejected, use
+/// [`HxReswap::lint`] to check for them.
+///
+/// Modifiers that are set to htmx's default value, i.e. `transition:false`,
+/// `swap:0ms`, `settle:20ms` and `focus-scroll:false`, are omitted from the
+/// header to keep it short. If the application changes these defaults in
+/// `htmx.config`, use [`HxReswap::force`] to always send them.
+///
+/// See <https://htmx.org/attributes/hx-swap/> for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HxReswap {
+    /// How the response will be swapped.
+    pub style: SwapOption,
+    /// Whether to use the View Transitions API for the swap.
+    pub transition: Option<bool>,
+    /// Delay between receiving the response and swapping the content.
+    pub swap_delay: Option<Duration>,
+    /// Delay between the swap and the settle step.
+    pub settle_delay: Option<Duration>,
+    /// Where to scroll after the swap.
+    pub scroll: Option<SwapTarget>,
+    /// What to show in the viewport after the swap.
+    pub show: Option<SwapTarget>,
+    /// Whether to scroll to the focused element after the swap.
+    pub focus_scroll: Option<bool>,
+    /// Whether to send modifiers that are set to htmx's default value.
+    pub force: bool,
+}
+
+impl HxReswap {
+    /// `innerHTML` without modifiers.
+    pub const INNER_HTML: Self = Self::new(SwapOption::InnerHtml);
+    /// `outerHTML` without modifiers.
+    pub const OUTER_HTML: Self = Self::new(SwapOption::OuterHtml);
+    /// `beforebegin` without modifiers.
+    pub const BEFORE_BEGIN: Self = Self::new(SwapOption::BeforeBegin);
+    /// `afterbegin` without modifiers.
+    pub const AFTER_BEGIN: Self = Self::new(SwapOption::AfterBegin);
+    /// `beforeend` without modifiers.
+    pub const BEFORE_END: Self = Self::new(SwapOption::BeforeEnd);
+    /// `afterend` without modifiers.
+    pub const AFTER_END: Self = Self::new(SwapOption::AfterEnd);
+    /// `delete` without modifiers.
+    pub const DELETE: Self = Self::new(SwapOption::Delete);
+    /// `none` without modifiers.
+    pub const NONE: Self = Self::new(SwapOption::None);
+
+    /// Creates new reswap with the given style and no modifiers.
+    pub const fn new(style: SwapOption) -> Self {
+        Self {
+            style,
+            transition: None,
+            swap_delay: None,
+            settle_delay: None,
+            scroll: None,
+            show: None,
+            focus_scroll: None,
+            force: false,
+        }
+    }
+
+    /// Creates new reswap with the given style that uses the View Transitions
+    /// API.
+    ///
+    /// See [`HxReswap::transition`] for browser support.
+    pub fn with_transition(style: SwapOption) -> Self {
+        Self::new(style).transition(true)
+    }
+
+    /// Sets whether to use the View Transitions API for the swap.
+    ///
+    /// Browsers that do not support the View Transitions API will swap the
+    /// content without a transition.
+    pub fn transition(mut self, transition: bool) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Sets the delay before the content is swapped.
+    ///
+    /// Independent of [`HxReswap::settle_delay`]. Zero is allowed.
+    pub fn swap_delay(mut self, delay: Duration) -> Self {
+        self.swap_delay = Some(delay);
+        self
+    }
+
+    /// Sets the delay between the swap and the settle step.
+    ///
+    /// Independent of [`HxReswap::swap_delay`]. Zero is allowed.
+    pub fn settle_delay(mut self, delay: Duration) -> Self {
+        self.settle_delay = Some(delay);
+        self
+    }
+
+    /// Scrolls to the given target after the swap.
+    pub fn scroll(mut self, target: SwapTarget) -> Self {
+        self.scroll = Some(target);
+        self
+    }
+
+    /// Shows the given target in the viewport after the swap.
+    pub fn show(mut self, target: SwapTarget) -> Self {
+        self.show = Some(target);
+        self
+    }
+
+    /// Sets whether to scroll to the focused element after the swap.
+    ///
+    /// htmx does not scroll to focused elements by default, unless
+    /// `htmx.config.defaultFocusScroll` is set.
+    pub fn focus_scroll(mut self, focus_scroll: bool) -> Self {
+        self.focus_scroll = Some(focus_scroll);
+        self
+    }
+
+    /// Sets whether to send modifiers that are set to htmx's default value,
+    /// which are omitted from the header otherwise.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    fn to_header_value(&self) -> Result<HeaderValue, HxError> {
+        self.validate()?;
+
+        HeaderValue::from_maybe_shared(self.to_string()).map_err(HxError::from)
+    }
+
+    fn write(&self, f: &mut impl fmt::Write, omit_defaults: bool) -> fmt::Result {
+        f.write_str(self.style.as_str())?;
+
+        if let Some(transition) = self.transition {
+            if transition || !omit_defaults {
+                write!(f, " transition:{transition}")?;
+            }
+        }
+        if let Some(delay) = self.swap_delay {
+            if !(omit_defaults && delay == DEFAULT_SWAP_DELAY) {
+                f.write_str(" swap:")?;
+                write_delay(f, delay)?;
+            }
+        }
+        if let Some(delay) = self.settle_delay {
+            if !(omit_defaults && delay == DEFAULT_SETTLE_DELAY) {
+                f.write_str(" settle:")?;
+                write_delay(f, delay)?;
+            }
+        }
+        if let Some(target) = &self.scroll {
+            write!(f, " scroll:{target}")?;
+        }
+        if let Some(target) = &self.show {
+            write!(f, " show:{target}")?;
+        }
+        if let Some(focus_scroll) = self.focus_scroll {
+            if focus_scroll || !omit_defaults {
+                write!(f, " focus-scroll:{focus_scroll}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a warning for combinations that are valid but likely a mistake,
+    /// such as `scroll` and `show` targeting the same position.
+    ///
+    /// The responder does not call this, so it is up to the caller to decide
+    /// whether to log, assert or ignore the warning.
+    pub fn lint(&self) -> Option<&'static str> {
+        match (&self.scroll, &self.show) {
+            (Some(scroll), Some(show)) if scroll == show => {
+                Some("scroll and show modifiers target the same position")
+            }
+            _ => None,
+        }
+    }
+
+    fn validate(&self) -> Result<(), HxError> {
+        let has_modifiers = self.swap_delay.is_some()
+            || self.settle_delay.is_some()
+            || self.scroll.is_some()
+            || self.show.is_some();
+        if has_modifiers && matches!(self.style, SwapOption::None | SwapOption::Delete) {
+            return Err(HxError::InvalidReswap(
+                "none and delete swaps can't have timing, scroll or show modifiers",
+            ));
+        }
+
+        if let Some(SwapTarget::Element(selector, _)) = &self.scroll {
+            if selector.is_empty() {
+                return Err(HxError::InvalidReswap("scroll selector is empty"));
+            }
+            if selector.contains(char::is_whitespace) {
+                return Err(HxError::InvalidReswap(
+                    "scroll selector contains whitespace",
+                ));
+            }
+        }
+        if let Some(SwapTarget::Element(selector, _)) = &self.show {
+            if selector.is_empty() {
+                return Err(HxError::InvalidReswap("show selector is empty"));
+            }
+            if selector.contains(char::is_whitespace) {
+                return Err(HxError::InvalidReswap("show selector contains whitespace"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the reswap as an `hx-swap` value, exactly as it is sent in the
+/// header. Modifiers set to htmx's default value are omitted unless
+/// [`HxReswap::force`] is set.
+impl fmt::Display for HxReswap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, !self.force)
+    }
+}
+
+/// Parses an `hx-swap` value such as `outerHTML swap:100ms scroll:#list:bottom`.
+///
+/// Delays accept `ms` and `s` suffixes, or a bare number of milliseconds, with
+/// an optional fraction down to nanoseconds. A modifier that is explicitly set
+/// to htmx's default value sets [`HxReswap::force`], so that the value is
+/// formatted again as it was written.
+impl FromStr for HxReswap {
+    type Err = HxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_ascii_whitespace();
+        let style = tokens
+            .next()
+            .and_then(SwapOption::from_name)
+            .ok_or(HxError::InvalidReswap("unknown swap style"))?;
+        let mut reswap = Self::new(style);
+
+        for token in tokens {
+            let (modifier, value) = token
+                .split_once(':')
+                .ok_or(HxError::InvalidReswap("modifier is missing a value"))?;
+
+            match modifier {
+                "transition" => reswap.transition = Some(parse_bool(value)?),
+                "swap" => reswap.swap_delay = Some(parse_delay(value)?),
+                "settle" => reswap.settle_delay = Some(parse_delay(value)?),
+                "scroll" => reswap.scroll = Some(value.parse()?),
+                "show" => reswap.show = Some(value.parse()?),
+                "focus-scroll" => reswap.focus_scroll = Some(parse_bool(value)?),
+                _ => return Err(HxError::InvalidReswap("unknown modifier")),
+            }
+        }
+
+        reswap.force = reswap.transition == Some(false)
+            || reswap.swap_delay == Some(DEFAULT_SWAP_DELAY)
+            || reswap.settle_delay == Some(DEFAULT_SETTLE_DELAY)
+            || reswap.focus_scroll == Some(false);
+
+        Ok(reswap)
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool, HxError> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(HxError::InvalidReswap("invalid boolean modifier value")),
+    }
+}
+
+fn parse_delay(value: &str) -> Result<Duration, HxError> {
+    let delay = if let Some(ms) = value.strip_suffix("ms") {
+        parse_decimal(ms, Duration::from_millis, 6)
+    } else if let Some(secs) = value.strip_suffix('s') {
+        parse_decimal(secs, Duration::from_secs, 9)
+    } else {
+        parse_decimal(value, Duration::from_millis, 6)
+    };
+
+    delay.ok_or(HxError::InvalidReswap("invalid delay"))
+}
+
+/// Parses a decimal number of units, where `digits` fractional digits make up
+/// a nanosecond.
+fn parse_decimal(value: &str, unit: fn(u64) -> Duration, digits: usize) -> Option<Duration> {
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    let (whole, fraction) = match value.split_once('.') {
+        Some((whole, fraction)) if is_digits(fraction) && fraction.len() <= digits => {
+            (whole, fraction)
+        }
+        Some(_) => return None,
+        None => (value, ""),
+    };
+    if !is_digits(whole) {
+        return None;
+    }
+
+    let nanos = format!("{fraction:0<digits$}").parse().ok()?;
+    unit(whole.parse().ok()?).checked_add(Duration::from_nanos(nanos))
+}
+
+/// Writes the delay in milliseconds, with a fraction if it isn't a whole
+/// number of them. htmx parses fractional delays as well.
+fn write_delay(f: &mut impl fmt::Write, delay: Duration) -> fmt::Result {
+    let nanos = delay.subsec_nanos() % 1_000_000;
+    write!(f, "{}", delay.as_millis())?;
+    if nanos != 0 {
+        let fraction = format!("{nanos:06}");
+        write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+
+    f.write_str("ms")
+}
 
 impl IntoResponseParts for HxReswap {
-    type Error = Infallible;
+    type Error = HxError;
 
     fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
-        res.headers_mut().insert(headers::HX_RESWAP, self.0.into());
+        res.headers_mut()
+            .insert(headers::HX_RESWAP, self.to_header_value()?);
+        AppliedHtmxHeaders::record(&mut res, headers::HX_RESWAP);
 
         Ok(res)
     }
 }
 
+/// Defaults to `innerHTML` without modifiers, which is the default swap style
+/// of htmx.
+///
+/// Setting it explicitly is usually unnecessary, but can be used to override a
+/// non-default `hx-swap` or `htmx.config.defaultSwapStyle` on the client.
+impl Default for HxReswap {
+    fn default() -> Self {
+        Self::new(SwapOption::InnerHtml)
+    }
+}
+
 impl From<SwapOption> for HxReswap {
     fn from(value: SwapOption) -> Self {
-        Self(value)
+        Self::new(value)
+    }
+}
+
+/// Target of the `scroll` and `show` swap modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwapTarget {
+    /// The top of the target element.
+    Top,
+    /// The bottom of the target element.
+    Bottom,
+    /// The given edge of the element matching a CSS selector.
+    Element(String, Edge),
+}
+
+impl FromStr for SwapTarget {
+    type Err = HxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "top" => Ok(SwapTarget::Top),
+            "bottom" => Ok(SwapTarget::Bottom),
+            _ => {
+                let (selector, edge) = s
+                    .rsplit_once(':')
+                    .ok_or(HxError::InvalidReswap("swap target is missing an edge"))?;
+                let edge = match edge {
+                    "top" => Edge::Top,
+                    "bottom" => Edge::Bottom,
+                    _ => return Err(HxError::InvalidReswap("invalid swap target edge")),
+                };
+
+                Ok(SwapTarget::Element(selector.to_owned(), edge))
+            }
+        }
+    }
+}
+
+impl fmt::Display for SwapTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwapTarget::Top => f.write_str("top"),
+            SwapTarget::Bottom => f.write_str("bottom"),
+            SwapTarget::Element(selector, edge) => write!(f, "{selector}:{edge}"),
+        }
+    }
+}
+
+/// Edge of an element used by the `scroll` and `show` swap modifiers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Edge {
+    /// Serialized as `top`.
+    Top,
+    /// Serialized as `bottom`.
+    Bottom,
+}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Edge::Top => f.write_str("top"),
+            Edge::Bottom => f.write_str("bottom"),
+        }
     }
 }
 
@@ -193,27 +692,34 @@ impl From<SwapOption> for HxReswap {
 /// A CSS selector that updates the target of the content update to a different
 /// element on the page.
 ///
+/// Static selectors are stored without allocating.
+///
 /// Will fail if the supplied String contains characters that are not visible
 /// ASCII (32-127).
 #[derive(Debug, Clone)]
-pub struct HxRetarget(pub String);
+pub struct HxRetarget(pub Cow<'static, str>);
 
 impl IntoResponseParts for HxRetarget {
     type Error = HxError;
 
     fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
-        res.headers_mut().insert(
-            headers::HX_RETARGET,
-            HeaderValue::from_maybe_shared(self.0)?,
-        );
+        res.headers_mut()
+            .insert(headers::HX_RETARGET, HeaderValue::from_str(&self.0)?);
+        AppliedHtmxHeaders::record(&mut res, headers::HX_RETARGET);
 
         Ok(res)
     }
 }
 
-impl<T: Into<String>> From<T> for HxRetarget {
-    fn from(value: T) -> Self {
-        Self(value.into())
+impl From<&'static str> for HxRetarget {
+    fn from(value: &'static str) -> Self {
+        Self(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for HxRetarget {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
     }
 }
 
@@ -222,32 +728,148 @@ impl<T: Into<String>> From<T> for HxRetarget {
 /// A CSS selector that allows you to choose which part of the response is used
 /// to be swapped in. Overrides an existing hx-select on the triggering element.
 ///
+/// Static selectors are stored without allocating.
+///
 /// Will fail if the supplied String contains characters that are not visible
 /// ASCII (32-127).
 #[derive(Debug, Clone)]
-pub struct HxReselect(pub String);
+pub struct HxReselect(pub Cow<'static, str>);
 
 impl IntoResponseParts for HxReselect {
     type Error = HxError;
 
     fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
-        res.headers_mut().insert(
-            headers::HX_RESELECT,
-            HeaderValue::from_maybe_shared(self.0)?,
-        );
+        res.headers_mut()
+            .insert(headers::HX_RESELECT, HeaderValue::from_str(&self.0)?);
+        AppliedHtmxHeaders::record(&mut res, headers::HX_RESELECT);
 
         Ok(res)
     }
 }
 
-impl<T: Into<String>> From<T> for HxReselect {
-    fn from(value: T) -> Self {
-        Self(value.into())
+impl From<&'static str> for HxReselect {
+    fn from(value: &'static str) -> Self {
+        Self(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for HxReselect {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
+    }
+}
+
+/// Response extension listing the htmx headers set by the responders of this
+/// crate.
+///
+/// Every responder records the name of its header here, so later layers such as
+/// logging or metrics can tell which htmx headers a response carries without
+/// parsing them. Names are stored in the casing of the [`headers`] constants
+/// and only listed once.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppliedHtmxHeaders(pub Vec<&'static str>);
+
+impl AppliedHtmxHeaders {
+    /// Returns `true` if the header `name` was applied, ignoring ASCII case.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0
+            .iter()
+            .any(|applied| applied.eq_ignore_ascii_case(name))
+    }
+
+    pub(crate) fn record(res: &mut ResponseParts, name: &'static str) {
+        let extensions = res.extensions_mut();
+        match extensions.get_mut::<AppliedHtmxHeaders>() {
+            Some(applied) if !applied.0.contains(&name) => applied.0.push(name),
+            Some(_) => {}
+            None => {
+                extensions.insert(AppliedHtmxHeaders(vec![name]));
+            }
+        }
+    }
+
+    pub(crate) fn forget(res: &mut ResponseParts, name: &'static str) {
+        if let Some(applied) = res.extensions_mut().get_mut::<AppliedHtmxHeaders>() {
+            applied.0.retain(|applied| *applied != name);
+        }
+    }
+}
+
+/// Responds with status `286`, which makes htmx stop polling.
+///
+/// htmx cancels an `every` trigger of `hx-trigger` when a response comes back
+/// with this status. The wrapped value is used as the rest of the response and
+/// is swapped in as usual.
+///
+/// See <https://htmx.org/docs/#polling> for more information.
+#[derive(Debug, Clone)]
+pub struct HxStopPolling<T>(pub T);
+
+impl<T: IntoResponse> IntoResponse for HxStopPolling<T> {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(286).expect("286 is a valid status code");
+
+        (status, self.0).into_response()
+    }
+}
+
+/// Responds with `422 Unprocessable Entity` and swaps the body into `target`.
+///
+/// Meant for rendering validation errors of a form into a region of the page,
+/// e.g. a list of errors next to the form, instead of the form's own target.
+/// The body replaces the inner html of the target unless changed with
+/// [`HxValidationError::swap`].
+///
+/// htmx does not swap error responses by default. htmx 2 swaps them when
+/// `htmx.config.responseHandling` contains e.g. `{code: "422", swap: true}`,
+/// htmx 1 needs a `htmx:beforeSwap` handler that sets `shouldSwap`.
+///
+/// ```
+/// use axum_htmx::response::HxValidationError;
+///
+/// let res = HxValidationError::new("#errors", "<li>Email is required</li>");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HxValidationError<T> {
+    /// Element to swap the errors into.
+    pub target: HxRetarget,
+    /// How the errors are swapped into the target.
+    pub reswap: HxReswap,
+    /// The rendered errors.
+    pub body: T,
+}
+
+impl<T> HxValidationError<T> {
+    /// Creates new validation error swapping `body` into `target`.
+    pub fn new(target: impl Into<HxRetarget>, body: T) -> Self {
+        Self {
+            target: target.into(),
+            reswap: HxReswap::INNER_HTML,
+            body,
+        }
+    }
+
+    /// Sets how the errors are swapped into the target.
+    pub fn swap(mut self, reswap: impl Into<HxReswap>) -> Self {
+        self.reswap = reswap.into();
+        self
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for HxValidationError<T> {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            self.target,
+            self.reswap,
+            self.body,
+        )
+            .into_response()
     }
 }
 
 /// Values of the `hx-swap` attribute.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SwapOption {
     /// Replace the inner html of the target element.
     InnerHtml,
@@ -294,17 +916,464 @@ impl ::serde::Serialize for SwapOption {
     }
 }
 
+impl SwapOption {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SwapOption::InnerHtml => HX_SWAP_INNER_HTML,
+            SwapOption::OuterHtml => HX_SWAP_OUTER_HTML,
+            SwapOption::BeforeBegin => HX_SWAP_BEFORE_BEGIN,
+            SwapOption::AfterBegin => HX_SWAP_AFTER_BEGIN,
+            SwapOption::BeforeEnd => HX_SWAP_BEFORE_END,
+            SwapOption::AfterEnd => HX_SWAP_AFTER_END,
+            SwapOption::Delete => HX_SWAP_DELETE,
+            SwapOption::None => HX_SWAP_NONE,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            HX_SWAP_INNER_HTML => SwapOption::InnerHtml,
+            HX_SWAP_OUTER_HTML => SwapOption::OuterHtml,
+            HX_SWAP_BEFORE_BEGIN => SwapOption::BeforeBegin,
+            HX_SWAP_AFTER_BEGIN => SwapOption::AfterBegin,
+            HX_SWAP_BEFORE_END => SwapOption::BeforeEnd,
+            HX_SWAP_AFTER_END => SwapOption::AfterEnd,
+            HX_SWAP_DELETE => SwapOption::Delete,
+            HX_SWAP_NONE => SwapOption::None,
+            _ => return None,
+        })
+    }
+}
+
 impl From<SwapOption> for HeaderValue {
     fn from(value: SwapOption) -> Self {
-        match value {
-            SwapOption::InnerHtml => HeaderValue::from_static(HX_SWAP_INNER_HTML),
-            SwapOption::OuterHtml => HeaderValue::from_static(HX_SWAP_OUTER_HTML),
-            SwapOption::BeforeBegin => HeaderValue::from_static(HX_SWAP_BEFORE_BEGIN),
-            SwapOption::AfterBegin => HeaderValue::from_static(HX_SWAP_AFTER_BEGIN),
-            SwapOption::BeforeEnd => HeaderValue::from_static(HX_SWAP_BEFORE_END),
-            SwapOption::AfterEnd => HeaderValue::from_static(HX_SWAP_AFTER_END),
-            SwapOption::Delete => HeaderValue::from_static(HX_SWAP_DELETE),
-            SwapOption::None => HeaderValue::from_static(HX_SWAP_NONE),
+        HeaderValue::from_static(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applied_htmx_headers() {
+        let res = (
+            HxRetarget::from("#main"),
+            HxReswap::OUTER_HTML,
+            HxRefresh(true),
+            HxRetarget::from("#other"),
+            HxRefresh(false),
+            "",
+        )
+            .into_response();
+
+        let applied = res.extensions().get::<AppliedHtmxHeaders>().unwrap();
+        assert_eq!(applied.0, [headers::HX_RETARGET, headers::HX_RESWAP]);
+        assert!(applied.contains("hx-reswap"));
+        assert!(!applied.contains(headers::HX_REFRESH));
+
+        let res = "".into_response();
+        assert!(res.extensions().get::<AppliedHtmxHeaders>().is_none());
+    }
+
+    #[test]
+    fn test_redirect_same_origin() {
+        let base: Uri = "https://example.com/login".parse().unwrap();
+
+        let redirect = HxRedirect::same_origin("https://Example.com/dashboard", &base).unwrap();
+        assert_eq!(redirect.0, "https://example.com/dashboard");
+
+        assert!(HxRedirect::same_origin("/dashboard?tab=1", &base).is_ok());
+        assert!(HxRedirect::same_origin("/account/../settings", &base).is_ok());
+
+        for target in [
+            "https://evil.com/",
+            "http://example.com/",
+            "https://example.com:8443/",
+            "//evil.com/",
+            "/\\evil.com",
+            "evil.com",
+            "javascript:alert(1)",
+            "not a uri",
+        ] {
+            assert!(
+                matches!(
+                    HxRedirect::same_origin(target, &base),
+                    Err(HxError::InvalidRedirect(_))
+                ),
+                "{target} should be rejected"
+            );
+        }
+
+        // Paths relative to the current document are not resolved.
+        for target in [
+            "dashboard",
+            "dashboard/settings",
+            "./dashboard",
+            "../settings",
+        ] {
+            assert!(
+                matches!(
+                    HxRedirect::same_origin(target, &base),
+                    Err(HxError::InvalidRedirect("relative path"))
+                ),
+                "{target} should be rejected"
+            );
+        }
+
+        let relative: Uri = "/login".parse().unwrap();
+        assert!(HxRedirect::same_origin("https://example.com/", &relative).is_err());
+        assert!(HxRedirect::same_origin("/dashboard", &relative).is_ok());
+    }
+
+    #[test]
+    fn test_stop_polling() {
+        let res = HxStopPolling((HxRetarget::from("#status"), "done")).into_response();
+        assert_eq!(res.status().as_u16(), 286);
+        assert_eq!(res.headers().get(headers::HX_RETARGET).unwrap(), "#status");
+    }
+
+    #[test]
+    fn test_validation_error() {
+        let res = HxValidationError::new("#errors", "<li>Email is required</li>").into_response();
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(res.headers().get(headers::HX_RETARGET).unwrap(), "#errors");
+        assert_eq!(res.headers().get(headers::HX_RESWAP).unwrap(), "innerHTML");
+
+        let res = HxValidationError::new(String::from("#form"), "")
+            .swap(SwapOption::OuterHtml)
+            .into_response();
+        assert_eq!(res.headers().get(headers::HX_RETARGET).unwrap(), "#form");
+        assert_eq!(res.headers().get(headers::HX_RESWAP).unwrap(), "outerHTML");
+    }
+
+    #[test]
+    fn test_no_history() {
+        let res = (HxNoHistory, "").into_response();
+        assert_eq!(res.headers().get(headers::HX_PUSH_URL).unwrap(), "false");
+        assert!(res.headers().get(headers::HX_REPLACE_URL).is_none());
+    }
+
+    #[test]
+    fn test_refresh_last_write_wins() {
+        let res = (HxRefresh(true), "").into_response();
+        assert_eq!(res.headers().get(headers::HX_REFRESH).unwrap(), "true");
+
+        let res = (HxRefresh(false), "").into_response();
+        assert!(res.headers().get(headers::HX_REFRESH).is_none());
+
+        let res = (HxRefresh(true), HxRefresh(false), "").into_response();
+        assert!(res.headers().get(headers::HX_REFRESH).is_none());
+    }
+
+    #[test]
+    fn test_selectors_from_static_and_owned() {
+        let retarget = HxRetarget::from("#main");
+        assert!(matches!(retarget.0, Cow::Borrowed("#main")));
+
+        let retarget = HxRetarget::from(format!("#row-{}", 1));
+        assert!(matches!(&retarget.0, Cow::Owned(s) if s == "#row-1"));
+
+        let reselect = HxReselect::from("#content");
+        assert!(matches!(reselect.0, Cow::Borrowed("#content")));
+
+        let reselect = HxReselect::from(String::from("#content"));
+        assert!(matches!(reselect.0, Cow::Owned(_)));
+
+        let res = (retarget, reselect, "").into_response();
+        assert_eq!(res.headers().get(headers::HX_RETARGET).unwrap(), "#row-1");
+        assert_eq!(res.headers().get(headers::HX_RESELECT).unwrap(), "#content");
+    }
+
+    #[test]
+    fn test_reswap_default() {
+        assert_eq!(HxReswap::default().to_header_value().unwrap(), "innerHTML");
+    }
+
+    #[test]
+    fn test_reswap_scroll() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml);
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML");
+
+        let reswap = HxReswap::new(SwapOption::InnerHtml).scroll(SwapTarget::Top);
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML scroll:top");
+
+        let reswap = HxReswap::new(SwapOption::OuterHtml).scroll(SwapTarget::Bottom);
+        assert_eq!(reswap.to_header_value().unwrap(), "outerHTML scroll:bottom");
+
+        let reswap = HxReswap::new(SwapOption::BeforeEnd)
+            .scroll(SwapTarget::Element("#content".into(), Edge::Top));
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "beforeend scroll:#content:top"
+        );
+
+        let reswap =
+            HxReswap::new(SwapOption::InnerHtml).scroll(SwapTarget::Element("".into(), Edge::Top));
+        assert!(reswap.to_header_value().is_err());
+    }
+
+    #[test]
+    fn test_reswap_show() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml).show(SwapTarget::Top);
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML show:top");
+
+        let reswap = HxReswap::new(SwapOption::InnerHtml).show(SwapTarget::Bottom);
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML show:bottom");
+
+        let reswap = HxReswap::new(SwapOption::AfterEnd)
+            .scroll(SwapTarget::Element("#list".into(), Edge::Bottom))
+            .show(SwapTarget::Element("#content".into(), Edge::Top));
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "afterend scroll:#list:bottom show:#content:top"
+        );
+
+        let reswap =
+            HxReswap::new(SwapOption::InnerHtml).show(SwapTarget::Element("".into(), Edge::Bottom));
+        assert!(reswap.to_header_value().is_err());
+    }
+
+    #[test]
+    fn test_reswap_lint_same_scroll_and_show() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml)
+            .scroll(SwapTarget::Element("#list".into(), Edge::Top))
+            .show(SwapTarget::Element("#list".into(), Edge::Top));
+        assert_eq!(
+            reswap.lint(),
+            Some("scroll and show modifiers target the same position")
+        );
+        // Only a warning, the header is still produced.
+        assert!(reswap.to_header_value().is_ok());
+
+        let reswap = reswap.show(SwapTarget::Element("#list".into(), Edge::Bottom));
+        assert!(reswap.lint().is_none());
+    }
+
+    #[test]
+    fn test_reswap_focus_scroll() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml).focus_scroll(true);
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "innerHTML focus-scroll:true"
+        );
+
+        let reswap = HxReswap::with_transition(SwapOption::OuterHtml)
+            .settle_delay(Duration::from_millis(20))
+            .show(SwapTarget::Top)
+            .focus_scroll(false);
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "outerHTML transition:true show:top"
+        );
+    }
+
+    #[test]
+    fn test_reswap_constants() {
+        let cases = [
+            (HxReswap::INNER_HTML, "innerHTML"),
+            (HxReswap::OUTER_HTML, "outerHTML"),
+            (HxReswap::BEFORE_BEGIN, "beforebegin"),
+            (HxReswap::AFTER_BEGIN, "afterbegin"),
+            (HxReswap::BEFORE_END, "beforeend"),
+            (HxReswap::AFTER_END, "afterend"),
+            (HxReswap::DELETE, "delete"),
+            (HxReswap::NONE, "none"),
+        ];
+
+        for (reswap, expected) in cases {
+            let res = (reswap, "").into_response();
+            assert_eq!(res.headers().get(headers::HX_RESWAP).unwrap(), expected);
         }
     }
+
+    #[test]
+    fn test_reswap_none_with_modifiers() {
+        let reswap = HxReswap::new(SwapOption::None).scroll(SwapTarget::Top);
+        assert!(matches!(
+            reswap.to_header_value(),
+            Err(HxError::InvalidReswap(_))
+        ));
+
+        let reswap = HxReswap::new(SwapOption::Delete).swap_delay(Duration::from_millis(100));
+        assert!(reswap.to_header_value().is_err());
+
+        let reswap = HxReswap::new(SwapOption::None).show(SwapTarget::Bottom);
+        assert!(reswap.to_header_value().is_err());
+
+        let reswap = HxReswap::new(SwapOption::None).transition(true);
+        assert_eq!(reswap.to_header_value().unwrap(), "none transition:true");
+    }
+
+    #[test]
+    fn test_reswap_omits_defaults() {
+        let reswap = HxReswap::new(SwapOption::OuterHtml)
+            .transition(false)
+            .swap_delay(Duration::ZERO)
+            .settle_delay(Duration::from_millis(20))
+            .focus_scroll(false);
+        let res = (reswap.clone(), "").into_response();
+        assert_eq!(res.headers().get(headers::HX_RESWAP).unwrap(), "outerHTML");
+        assert_eq!(reswap.to_string(), "outerHTML");
+
+        let res = (reswap.force(true), "").into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_RESWAP).unwrap(),
+            "outerHTML transition:false swap:0ms settle:20ms focus-scroll:false"
+        );
+
+        let reswap = HxReswap::new(SwapOption::OuterHtml)
+            .transition(true)
+            .swap_delay(Duration::from_millis(100))
+            .settle_delay(Duration::ZERO)
+            .focus_scroll(true);
+        let res = (reswap, "").into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_RESWAP).unwrap(),
+            "outerHTML transition:true swap:100ms settle:0ms focus-scroll:true"
+        );
+    }
+
+    #[test]
+    fn test_reswap_round_trip() {
+        let cases = [
+            HxReswap::default(),
+            HxReswap::with_transition(SwapOption::OuterHtml),
+            HxReswap::new(SwapOption::None)
+                .transition(false)
+                .force(true),
+            HxReswap::new(SwapOption::BeforeEnd)
+                .swap_delay(Duration::from_millis(100))
+                .settle_delay(Duration::from_millis(0)),
+            HxReswap::new(SwapOption::AfterBegin)
+                .scroll(SwapTarget::Top)
+                .show(SwapTarget::Bottom),
+            HxReswap::with_transition(SwapOption::Delete)
+                .swap_delay(Duration::from_secs(1))
+                .scroll(SwapTarget::Element("a:hover".into(), Edge::Bottom))
+                .show(SwapTarget::Element("#content".into(), Edge::Top)),
+            HxReswap::new(SwapOption::InnerHtml)
+                .focus_scroll(false)
+                .force(true),
+            HxReswap::new(SwapOption::OuterHtml)
+                .settle_delay(Duration::from_millis(20))
+                .force(true),
+            HxReswap::new(SwapOption::InnerHtml)
+                .swap_delay(Duration::from_micros(1500))
+                .settle_delay(Duration::from_nanos(1)),
+        ];
+
+        for reswap in cases {
+            let parsed = HxReswap::from_str(&reswap.to_string()).unwrap();
+            assert_eq!(parsed, reswap);
+        }
+    }
+
+    #[test]
+    fn test_reswap_parse() {
+        let reswap: HxReswap = "innerHTML swap:1s settle:250".parse().unwrap();
+        assert_eq!(reswap.swap_delay, Some(Duration::from_secs(1)));
+        assert_eq!(reswap.settle_delay, Some(Duration::from_millis(250)));
+
+        assert!("innerhtml".parse::<HxReswap>().is_err());
+        assert!("".parse::<HxReswap>().is_err());
+        assert!("innerHTML scroll".parse::<HxReswap>().is_err());
+        assert!("innerHTML scroll:#content".parse::<HxReswap>().is_err());
+        assert!("innerHTML ignoreTitle:true".parse::<HxReswap>().is_err());
+        assert!("innerHTML focus-scroll:1".parse::<HxReswap>().is_err());
+        assert!("innerHTML transition:yes".parse::<HxReswap>().is_err());
+        assert!("innerHTML swap:1.ms".parse::<HxReswap>().is_err());
+        assert!("innerHTML swap:1.0000001ms".parse::<HxReswap>().is_err());
+        assert!("innerHTML swap:-1ms".parse::<HxReswap>().is_err());
+    }
+
+    #[test]
+    fn test_reswap_display_matches_header() {
+        let reswap = HxReswap::new(SwapOption::OuterHtml).transition(false);
+        assert_eq!(reswap.to_string(), "outerHTML");
+        assert_eq!(reswap.to_header_value().unwrap(), "outerHTML");
+
+        // `force` only affects which modifiers are written, it isn't written
+        // itself.
+        let reswap = reswap.force(true);
+        assert_eq!(reswap.to_string(), "outerHTML transition:false");
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "outerHTML transition:false"
+        );
+        assert_eq!(reswap.to_string().parse::<HxReswap>().unwrap(), reswap);
+
+        assert!("outerHTML force:true".parse::<HxReswap>().is_err());
+        let parsed: HxReswap = "outerHTML settle:20ms".parse().unwrap();
+        assert!(parsed.force);
+        assert_eq!(parsed.to_string(), "outerHTML settle:20ms");
+    }
+
+    #[test]
+    fn test_reswap_fractional_delay() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml)
+            .swap_delay(Duration::from_micros(1500))
+            .settle_delay(Duration::new(2, 1));
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "innerHTML swap:1.5ms settle:2000.000001ms"
+        );
+        assert_eq!(reswap.to_string().parse::<HxReswap>().unwrap(), reswap);
+
+        let reswap: HxReswap = "innerHTML swap:0.25s settle:0.5".parse().unwrap();
+        assert_eq!(reswap.swap_delay, Some(Duration::from_millis(250)));
+        assert_eq!(reswap.settle_delay, Some(Duration::from_micros(500)));
+    }
+
+    #[test]
+    fn test_reswap_selector_with_whitespace() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml)
+            .scroll(SwapTarget::Element("closest div".into(), Edge::Top));
+        assert!(matches!(
+            reswap.to_header_value(),
+            Err(HxError::InvalidReswap(_))
+        ));
+        assert!(reswap.to_string().parse::<HxReswap>().is_err());
+
+        let reswap = HxReswap::new(SwapOption::InnerHtml)
+            .show(SwapTarget::Element("#list\tli".into(), Edge::Bottom));
+        assert!(reswap.to_header_value().is_err());
+    }
+
+    #[test]
+    fn test_edge_display() {
+        assert_eq!(Edge::Top.to_string(), "top");
+        assert_eq!(Edge::Bottom.to_string(), "bottom");
+    }
+
+    #[test]
+    fn test_reswap_transition() {
+        let reswap = HxReswap::with_transition(SwapOption::OuterHtml);
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "outerHTML transition:true"
+        );
+
+        let reswap = HxReswap::new(SwapOption::InnerHtml)
+            .transition(false)
+            .scroll(SwapTarget::Top);
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML scroll:top");
+    }
+
+    #[test]
+    fn test_reswap_delays() {
+        let reswap = HxReswap::new(SwapOption::InnerHtml).swap_delay(Duration::from_millis(100));
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML swap:100ms");
+
+        let reswap = HxReswap::new(SwapOption::InnerHtml).settle_delay(Duration::from_secs(1));
+        assert_eq!(reswap.to_header_value().unwrap(), "innerHTML settle:1000ms");
+
+        let reswap = HxReswap::new(SwapOption::OuterHtml)
+            .settle_delay(Duration::ZERO)
+            .swap_delay(Duration::from_millis(50));
+        assert_eq!(
+            reswap.to_header_value().unwrap(),
+            "outerHTML swap:50ms settle:0ms"
+        );
+    }
 }
@@ -0,0 +1,113 @@
+use axum_core::response::{IntoResponseParts, ResponseParts};
+use http::Uri;
+
+use crate::{
+    response::{
+        HxEvent, HxLocation, HxPushUrl, HxRedirect, HxRefresh, HxReplaceUrl, HxReselect,
+        HxResponseTrigger, HxReswap, HxRetarget,
+    },
+    HxError,
+};
+
+/// A single htmx response header as data.
+///
+/// This is useful when the header to set is computed, e.g. from a `match` on
+/// the result of the application logic, as every command can be returned from
+/// the same code path. Each variant behaves exactly like the corresponding
+/// responder.
+///
+/// ```
+/// use axum_htmx::response::{HxCommand, HxEvent};
+///
+/// enum Outcome {
+///     Saved,
+///     Expired,
+/// }
+///
+/// fn command(outcome: Outcome) -> HxCommand {
+///     match outcome {
+///         Outcome::Saved => HxCommand::Trigger(HxEvent::new("saved")),
+///         Outcome::Expired => HxCommand::Redirect("/login".parse().unwrap()),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum HxCommand {
+    /// See [`HxLocation`].
+    Location(HxLocation),
+    /// See [`HxPushUrl`].
+    PushUrl(Uri),
+    /// See [`HxRedirect`].
+    Redirect(Uri),
+    /// See [`HxRefresh`].
+    Refresh,
+    /// See [`HxReplaceUrl`].
+    ReplaceUrl(Uri),
+    /// See [`HxReswap`].
+    Reswap(HxReswap),
+    /// See [`HxRetarget`].
+    Retarget(String),
+    /// See [`HxReselect`].
+    Reselect(String),
+    /// Triggers the event as soon as the response is received, see
+    /// [`HxResponseTrigger`].
+    Trigger(HxEvent),
+}
+
+impl IntoResponseParts for HxCommand {
+    type Error = HxError;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        match self {
+            HxCommand::Location(location) => location.into_response_parts(res),
+            HxCommand::PushUrl(uri) => HxPushUrl(uri).into_response_parts(res),
+            HxCommand::Redirect(uri) => HxRedirect(uri).into_response_parts(res),
+            HxCommand::Refresh => Ok(HxRefresh(true)
+                .into_response_parts(res)
+                .unwrap_or_else(|never| match never {})),
+            HxCommand::ReplaceUrl(uri) => HxReplaceUrl(uri).into_response_parts(res),
+            HxCommand::Reswap(reswap) => reswap.into_response_parts(res),
+            HxCommand::Retarget(selector) => HxRetarget::from(selector).into_response_parts(res),
+            HxCommand::Reselect(selector) => HxReselect::from(selector).into_response_parts(res),
+            HxCommand::Trigger(event) => {
+                HxResponseTrigger::normal([event]).into_response_parts(res)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_core::response::IntoResponse;
+
+    use super::*;
+    use crate::{headers, response::SwapOption};
+
+    #[test]
+    fn test_commands() {
+        let res = (
+            HxCommand::Retarget("#main".into()),
+            HxCommand::Reswap(HxReswap::new(SwapOption::OuterHtml)),
+            HxCommand::Trigger(HxEvent::new("first")),
+            HxCommand::Trigger(HxEvent::new("second")),
+            HxCommand::PushUrl("/items/1".parse().unwrap()),
+            HxCommand::Refresh,
+            "",
+        )
+            .into_response();
+
+        let headers = res.headers();
+        assert_eq!(headers.get(headers::HX_RETARGET).unwrap(), "#main");
+        assert_eq!(headers.get(headers::HX_RESWAP).unwrap(), "outerHTML");
+        assert_eq!(headers.get(headers::HX_TRIGGER).unwrap(), "first, second");
+        assert_eq!(headers.get(headers::HX_PUSH_URL).unwrap(), "/items/1");
+        assert_eq!(headers.get(headers::HX_REFRESH).unwrap(), "true");
+    }
+
+    #[test]
+    fn test_command_error() {
+        let res = (HxCommand::Reselect("bad\nselector".into()), "").into_response();
+        assert!(res.status().is_server_error());
+    }
+}
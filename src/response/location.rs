@@ -66,22 +66,159 @@ impl HxLocation {
         })
     }
 
+    /// Creates location to the given [`Uri`], meant to be chained with the
+    /// option builders.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use axum_htmx::response::{HxLocation, SwapOption};
+    ///
+    /// let location = HxLocation::to("/profile".parse().unwrap())
+    ///     .target("#main")
+    ///     .swap(SwapOption::OuterHtml);
+    /// # }
+    /// ```
+    pub fn to(uri: Uri) -> Self {
+        Self::from_uri(uri)
+    }
+
+    /// Sets the source element of the request.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn source(mut self, selector: impl Into<String>) -> Self {
+        self.options.source = Some(selector.into());
+        self
+    }
+
+    /// Sets the target to swap the response into.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn target(mut self, selector: impl Into<String>) -> Self {
+        self.options.target = Some(selector.into());
+        self
+    }
+
+    /// Sets how the response will be swapped in relative to the target.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn swap(mut self, style: crate::response::SwapOption) -> Self {
+        self.options.swap = Some(style);
+        self
+    }
+
+    /// Sets the content to select from the response.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn select(mut self, selector: impl Into<String>) -> Self {
+        self.options.select = Some(selector.into());
+        self
+    }
+
+    /// Sets the `values` option from form-style key/value pairs.
+    ///
+    /// htmx submits `values` as regular request parameters, just like form
+    /// fields, rather than as a JSON body. Every value is therefore a string,
+    /// and repeated keys are collected into an array.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn values_form<K, V>(mut self, pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        use serde_json::{map::Entry, Map, Value};
+
+        let mut values = Map::new();
+        for (key, value) in pairs {
+            let value = Value::String(value.into());
+
+            match values.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(mut entry) => match entry.get_mut() {
+                    Value::Array(array) => array.push(value),
+                    existing => *existing = Value::Array(vec![existing.take(), value]),
+                },
+            }
+        }
+
+        self.options.values = Some(Value::Object(values));
+        self
+    }
+
+    /// Adds a header to be submitted with the request.
+    ///
+    /// Will fail if `name` is not a valid header name or `value` contains
+    /// characters that are not visible ASCII (32-127).
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn header(
+        mut self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<Self, HxError> {
+        use serde_json::{Map, Value};
+
+        let name = http::HeaderName::from_str(name.as_ref())?;
+        HeaderValue::from_str(value.as_ref())?;
+
+        let headers = self
+            .options
+            .headers
+            .get_or_insert_with(|| Value::Object(Map::new()));
+        if !headers.is_object() {
+            *headers = Value::Object(Map::new());
+        }
+
+        headers
+            .as_object_mut()
+            .expect("headers is an object")
+            .insert(name.as_str().to_owned(), value.as_ref().into());
+
+        Ok(self)
+    }
+
+    /// Adds multiple headers to be submitted with the request.
+    ///
+    /// See [`HxLocation::header`] for more information.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+    pub fn headers<K, V>(self, headers: impl IntoIterator<Item = (K, V)>) -> Result<Self, HxError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        headers
+            .into_iter()
+            .try_fold(self, |loc, (name, value)| loc.header(name, value))
+    }
+
+    /// Converts the location into a full page redirect to the same uri.
+    ///
+    /// A redirect loads the whole page, so `source`, `event`, `handler`,
+    /// `target`, `swap` and `select` are dropped. A redirect can't submit
+    /// `values` or `headers` though, so the location is returned unchanged as
+    /// the error if either is set.
+    #[allow(clippy::result_large_err)]
+    pub fn try_into_redirect(self) -> Result<crate::response::HxRedirect, Self> {
+        #[cfg(feature = "serde")]
+        if self.options.values.is_some() || self.options.headers.is_some() {
+            return Err(self);
+        }
+
+        Ok(crate::response::HxRedirect(self.uri))
+    }
+
     #[cfg(feature = "serde")]
     fn into_header_with_options(self) -> Result<String, HxError> {
         if self.options.is_default() {
             return Ok(self.uri.to_string());
         }
 
-        #[derive(::serde::Serialize)]
-        struct LocWithOpts {
-            path: String,
-            #[serde(flatten)]
-            opts: LocationOptions,
-        }
-
-        let loc_with_opts = LocWithOpts {
-            path: self.uri.to_string(),
-            opts: self.options,
+        let loc_with_opts = HxLocationOptions {
+            path: self.uri,
+            options: self.options,
         };
 
         Ok(serde_json::to_string(&loc_with_opts)?)
@@ -110,6 +247,14 @@ impl<'a> TryFrom<&'a str> for HxLocation {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+impl From<HxLocationOptions> for HxLocation {
+    fn from(HxLocationOptions { path, options }: HxLocationOptions) -> Self {
+        Self::from_uri_with_options(path, options)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
 impl<'a> TryFrom<(&'a str, LocationOptions)> for HxLocation {
@@ -133,6 +278,7 @@ impl IntoResponseParts for HxLocation {
             headers::HX_LOCATION,
             HeaderValue::from_maybe_shared(header)?,
         );
+        crate::response::AppliedHtmxHeaders::record(&mut res, headers::HX_LOCATION);
 
         Ok(res)
     }
@@ -175,6 +321,9 @@ pub struct LocationOptions {
     /// Headers to submit with the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<serde_json::Value>,
+    /// Allows you to select the content you want swapped from a response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub select: Option<String>,
 }
 
 #[cfg(feature = "serde")]
@@ -189,6 +338,7 @@ impl LocationOptions {
             swap: None,
             values: None,
             headers: None,
+            select: None,
         } = self
         else {
             return false;
@@ -198,10 +348,59 @@ impl LocationOptions {
     }
 }
 
+/// The object form of the `HX-Location` header.
+///
+/// Contains the `path` of the new location together with all of its
+/// [`LocationOptions`], which are serialized next to it. Options that are
+/// `None` are omitted. Can be converted into an [`HxLocation`].
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HxLocationOptions {
+    /// Uri of the new location.
+    #[serde(serialize_with = "serialize_uri")]
+    pub path: Uri,
+    /// Extra options.
+    #[serde(flatten)]
+    pub options: LocationOptions,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_uri<S: ::serde::Serializer>(uri: &Uri, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(uri)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_location_redirect_conversion() {
+        let loc = crate::response::HxRedirect("/login".parse().unwrap()).into_location();
+        assert_eq!(loc.uri, "/login");
+
+        let redirect = HxLocation::try_from("/login")
+            .unwrap()
+            .try_into_redirect()
+            .unwrap();
+        assert_eq!(redirect.0, "/login");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_location_into_redirect_drops_options() {
+        let redirect = HxLocation::to("/profile".parse().unwrap())
+            .target("#main")
+            .select("#content")
+            .try_into_redirect()
+            .unwrap();
+        assert_eq!(redirect.0, "/profile");
+
+        let loc = HxLocation::to("/profile".parse().unwrap()).values_form([("tab", "info")]);
+        let loc = loc.try_into_redirect().unwrap_err();
+        assert!(loc.options.values.is_some());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serialize_location() {
@@ -223,4 +422,118 @@ mod tests {
             r#"{"path":"/foo","event":"click","swap":"innerHTML"}"#
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialize_location_values_form() {
+        let loc = HxLocation::try_from("/foo").unwrap().values_form([
+            ("name", "htmx"),
+            ("tag", "a"),
+            ("tag", "b"),
+            ("tag", "c"),
+        ]);
+        assert_eq!(
+            loc.into_header_with_options().unwrap(),
+            r#"{"path":"/foo","values":{"name":"htmx","tag":["a","b","c"]}}"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialize_location_headers() {
+        let loc = HxLocation::try_from("/foo")
+            .unwrap()
+            .header("X-CSRF-Token", "abc123")
+            .unwrap()
+            .headers([("X-Tenant", "acme"), ("X-Locale", "en")])
+            .unwrap();
+        assert_eq!(
+            loc.into_header_with_options().unwrap(),
+            r#"{"path":"/foo","headers":{"x-csrf-token":"abc123","x-locale":"en","x-tenant":"acme"}}"#
+        );
+
+        let loc = HxLocation::try_from("/foo").unwrap();
+        assert!(loc.clone().header("bad header", "value").is_err());
+        assert!(loc.header("X-Token", "bad\nvalue").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_location_fluent() {
+        use axum_core::response::IntoResponse;
+
+        use crate::response::SwapOption;
+
+        let res = (
+            HxLocation::to("/profile".parse().unwrap())
+                .target("#main")
+                .swap(SwapOption::OuterHtml)
+                .select("#content"),
+            "",
+        )
+            .into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_LOCATION).unwrap(),
+            r##"{"path":"/profile","target":"#main","swap":"outerHTML","select":"#content"}"##
+        );
+
+        let res = (HxLocation::to("/profile".parse().unwrap()), "").into_response();
+        assert_eq!(res.headers().get(headers::HX_LOCATION).unwrap(), "/profile");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_location_source() {
+        let loc = HxLocation::to("/foo".parse().unwrap()).source("#button");
+        assert_eq!(
+            loc.into_header_with_options().unwrap(),
+            r##"{"path":"/foo","source":"#button"}"##
+        );
+
+        let loc = HxLocation::to("/foo".parse().unwrap()).target("#main");
+        assert_eq!(
+            loc.into_header_with_options().unwrap(),
+            r##"{"path":"/foo","target":"#main"}"##
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_location_from_options() {
+        let opts = HxLocationOptions {
+            path: "/foo".parse().unwrap(),
+            options: LocationOptions {
+                target: Some("#main".into()),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&opts).unwrap(),
+            r##"{"path":"/foo","target":"#main"}"##
+        );
+
+        let loc = HxLocation::from(opts);
+        assert_eq!(
+            loc.into_header_with_options().unwrap(),
+            r##"{"path":"/foo","target":"#main"}"##
+        );
+
+        let opts = HxLocationOptions {
+            path: "/bar".parse().unwrap(),
+            options: LocationOptions {
+                source: Some("#button".into()),
+                event: Some("click".into()),
+                handler: Some("handle".into()),
+                target: Some("#main".into()),
+                swap: Some(crate::response::SwapOption::OuterHtml),
+                values: Some(serde_json::json!({"id": 1})),
+                headers: Some(serde_json::json!({"x-token": "abc"})),
+                select: Some("#content".into()),
+            },
+        };
+        assert_eq!(
+            HxLocation::from(opts).into_header_with_options().unwrap(),
+            r##"{"path":"/bar","source":"#button","event":"click","handler":"handle","target":"#main","swap":"outerHTML","values":{"id":1},"headers":{"x-token":"abc"},"select":"#content"}"##
+        );
+    }
 }
@@ -24,22 +24,69 @@ impl HxEvent {
     }
 
     /// Creates new event with data.
+    ///
+    /// Fails with [`HxError::TriggerSerialization`] if `data` can't be
+    /// serialized.
     #[cfg(feature = "serde")]
     #[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
     pub fn new_with_data<T: ::serde::Serialize>(
         name: impl AsRef<str>,
         data: T,
-    ) -> Result<Self, serde_json::Error> {
-        let data = serde_json::to_value(data)?;
+    ) -> Result<Self, HxError> {
+        let name = name.as_ref().to_owned();
+        let data = match serde_json::to_value(data) {
+            Ok(data) => data,
+            Err(source) => {
+                return Err(HxError::TriggerSerialization {
+                    event: name,
+                    source,
+                })
+            }
+        };
 
         Ok(Self {
-            name: name.as_ref().to_owned(),
+            name,
             #[cfg(feature = "serde")]
             data: Some(data),
         })
     }
 }
 
+/// Converts a type into an [`HxEvent`].
+///
+/// This can be implemented for domain events of an application, which can then
+/// be collected into an [`HxResponseTrigger`]:
+///
+/// ```
+/// use axum_htmx::response::{HxEvent, HxResponseTrigger, IntoHxEvent};
+///
+/// enum AppEvent {
+///     UserCreated,
+///     UserDeleted,
+/// }
+///
+/// impl IntoHxEvent for AppEvent {
+///     fn into_hx_event(self) -> HxEvent {
+///         match self {
+///             AppEvent::UserCreated => HxEvent::new("user-created"),
+///             AppEvent::UserDeleted => HxEvent::new("user-deleted"),
+///         }
+///     }
+/// }
+///
+/// let events = vec![AppEvent::UserCreated, AppEvent::UserDeleted];
+/// let trigger = HxResponseTrigger::normal(events.into_iter().map(IntoHxEvent::into_hx_event));
+/// ```
+pub trait IntoHxEvent {
+    fn into_hx_event(self) -> HxEvent;
+}
+
+impl IntoHxEvent for HxEvent {
+    fn into_hx_event(self) -> HxEvent {
+        self
+    }
+}
+
 impl<N: AsRef<str>> From<N> for HxEvent {
     fn from(name: N) -> Self {
         Self {
@@ -61,24 +108,73 @@ fn events_to_header_value(events: Vec<HxEvent>) -> Result<http::HeaderValue, HxE
     http::HeaderValue::from_str(&header).map_err(Into::into)
 }
 
+fn header_value_to_events(value: &http::HeaderValue) -> Option<Vec<HxEvent>> {
+    let value = value.to_str().ok()?;
+
+    if value.trim_start().starts_with('{') {
+        // json encoded events can only be parsed with serde.
+        #[cfg(feature = "serde")]
+        return serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(value)
+            .ok()
+            .map(|events| {
+                events
+                    .into_iter()
+                    .map(|(name, data)| HxEvent {
+                        name,
+                        data: Some(data),
+                    })
+                    .collect()
+            });
+
+        #[cfg(not(feature = "serde"))]
+        return None;
+    }
+
+    let events = value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(HxEvent::new)
+        .collect();
+
+    Some(events)
+}
+
 #[cfg(feature = "serde")]
 fn events_to_header_value(events: Vec<HxEvent>) -> Result<http::HeaderValue, HxError> {
-    use std::collections::HashMap;
+    use std::collections::{hash_map::Entry, HashMap};
 
     use http::HeaderValue;
     use serde_json::Value;
 
+    /// Serializes events as a JSON object with keys in the order of the events.
+    struct OrderedEvents(Vec<(String, Value)>);
+
+    impl ::serde::Serialize for OrderedEvents {
+        fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(self.0.iter().map(|(name, data)| (name, data)))
+        }
+    }
+
     let with_data = events.iter().any(|e| e.data.is_some());
 
     let header_value = if with_data {
         // at least one event contains data so the header_value needs to be json
-        // encoded.
-        let header_value = events
-            .into_iter()
-            .map(|e| (e.name, e.data.unwrap_or_default()))
-            .collect::<HashMap<String, Value>>();
+        // encoded. A repeated event keeps its first position and its last data.
+        let mut ordered: Vec<(String, Value)> = Vec::with_capacity(events.len());
+        let mut positions: HashMap<String, usize> = HashMap::with_capacity(events.len());
+        for event in events {
+            let data = event.data.unwrap_or_default();
+            match positions.entry(event.name) {
+                Entry::Occupied(entry) => ordered[*entry.get()].1 = data,
+                Entry::Vacant(entry) => {
+                    ordered.push((entry.key().clone(), data));
+                    entry.insert(ordered.len() - 1);
+                }
+            }
+        }
 
-        serde_json::to_string(&header_value)?
+        to_json_string(&OrderedEvents(ordered))?
     } else {
         // no event contains data, the event names can be put in the header
         // value separated by a comma.
@@ -92,6 +188,62 @@ fn events_to_header_value(events: Vec<HxEvent>) -> Result<http::HeaderValue, HxE
     HeaderValue::from_maybe_shared(header_value).map_err(HxError::from)
 }
 
+/// Serializes `value` with simd-json if the `simd-json` feature is enabled, and
+/// with serde_json otherwise. Both produce the same output, except that
+/// simd-json writes float exponents without a `+`, e.g. `1e300`.
+#[cfg(feature = "serde")]
+fn to_json_string<T: ::serde::Serialize>(value: &T) -> Result<String, HxError> {
+    #[cfg(feature = "simd-json")]
+    return simd_json::to_string(value)
+        .map_err(|err| HxError::Json(::serde::ser::Error::custom(err)));
+
+    #[cfg(not(feature = "simd-json"))]
+    return serde_json::to_string(value).map_err(HxError::from);
+}
+
+/// Formats a server-sent event that htmx can consume.
+///
+/// The htmx SSE extension triggers `sse:<name>` events for messages with the
+/// given `event` name, which lets servers drive client-side behaviour over an
+/// SSE stream in the same way as `HX-Trigger`. Multiline `data` is split into
+/// multiple `data:` fields at every `\r\n`, `\r` or `\n`, as required by the
+/// SSE format, so that the client receives the same lines, including trailing
+/// empty ones.
+///
+/// Will fail if `name` contains a line break, which would end the `event:`
+/// field and let the rest of the name inject other fields.
+///
+/// See <https://htmx.org/extensions/sse/> for more information.
+pub fn sse_trigger_event(name: impl AsRef<str>, data: impl AsRef<str>) -> Result<String, HxError> {
+    let name = name.as_ref();
+    if name.contains(['\r', '\n']) {
+        return Err(HxError::InvalidSseEvent("event name contains a line break"));
+    }
+
+    let mut event = format!("event: {name}\n");
+    let mut push_data = |line: &str| {
+        event.push_str("data: ");
+        event.push_str(line);
+        event.push('\n');
+    };
+
+    let mut rest = data.as_ref();
+    while let Some(end) = rest.find(['\r', '\n']) {
+        push_data(&rest[..end]);
+
+        let terminator = if rest[end..].starts_with("\r\n") {
+            2
+        } else {
+            1
+        };
+        rest = &rest[end + terminator..];
+    }
+    push_data(rest);
+
+    event.push('\n');
+    Ok(event)
+}
+
 /// Describes when should event be triggered.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -101,6 +253,16 @@ pub enum TriggerMode {
     AfterSwap,
 }
 
+impl TriggerMode {
+    fn header_name(self) -> &'static str {
+        match self {
+            TriggerMode::Normal => headers::HX_TRIGGER,
+            TriggerMode::AfterSettle => headers::HX_TRIGGER_AFTER_SETTLE,
+            TriggerMode::AfterSwap => headers::HX_TRIGGER_AFTER_SWAP,
+        }
+    }
+}
+
 /// The `HX-Trigger*` header.
 ///
 /// Allows you to trigger client-side events. Corresponds to `HX-Trigger`,
@@ -112,6 +274,11 @@ pub enum TriggerMode {
 ///
 /// See <https://htmx.org/headers/hx-trigger/> for more information.
 ///
+/// If the response already contains a header for the same mode, e.g. from
+/// another `HxResponseTrigger` or an inner middleware, the events are merged
+/// into it instead of replacing it. Without the `serde` feature, an existing
+/// JSON encoded header can't be merged and is replaced.
+///
 /// Note: An `HxResponseTrigger` implements `IntoResponseParts` and should be
 /// used before any other response object would consume the response parts.
 #[derive(Debug, Clone)]
@@ -153,6 +320,13 @@ impl HxResponseTrigger {
     pub fn after_swap<T: Into<HxEvent>>(events: impl IntoIterator<Item = T>) -> Self {
         Self::new(TriggerMode::AfterSwap, events)
     }
+
+    /// Returns `true` if the trigger contains no events.
+    ///
+    /// An empty trigger does not add any header to the response.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
 }
 
 impl<T> From<(TriggerMode, T)> for HxResponseTrigger
@@ -168,34 +342,349 @@ where
     }
 }
 
+/// Creates a normal trigger from a single event with its data.
+///
+/// ```
+/// use axum_htmx::response::HxResponseTrigger;
+/// use serde_json::json;
+///
+/// let trigger = HxResponseTrigger::from(("showToast", json!({"msg": "hi"})));
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+impl From<(&str, serde_json::Value)> for HxResponseTrigger {
+    fn from(event: (&str, serde_json::Value)) -> Self {
+        Self::from([event])
+    }
+}
+
+/// Creates a normal trigger from events with their data.
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+impl<const N: usize> From<[(&str, serde_json::Value); N]> for HxResponseTrigger {
+    fn from(events: [(&str, serde_json::Value); N]) -> Self {
+        Self::normal(events.map(|(name, data)| HxEvent {
+            name: name.to_owned(),
+            data: Some(data),
+        }))
+    }
+}
+
 impl IntoResponseParts for HxResponseTrigger {
     type Error = HxError;
 
     fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
-        if !self.events.is_empty() {
-            let header = match self.mode {
-                TriggerMode::Normal => headers::HX_TRIGGER,
-                TriggerMode::AfterSettle => headers::HX_TRIGGER_AFTER_SETTLE,
-                TriggerMode::AfterSwap => headers::HX_TRIGGER_AFTER_SETTLE,
-            };
+        if !self.is_empty() {
+            let header = self.mode.header_name();
+
+            let mut events = self.events;
+            if let Some(existing) = res.headers().get(header) {
+                if let Some(mut existing) = header_value_to_events(existing) {
+                    existing.append(&mut events);
+                    events = existing;
+                }
+            }
 
             res.headers_mut()
-                .insert(header, events_to_header_value(self.events)?);
+                .insert(header, events_to_header_value(events)?);
+            crate::response::AppliedHtmxHeaders::record(&mut res, header);
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+impl HxResponseTrigger {
+    /// Creates new normal trigger for a single event, whose data is computed
+    /// by `payload` only when the trigger is applied to a response.
+    ///
+    /// This avoids computing an expensive payload when the response never
+    /// gets the trigger, e.g. because an earlier part of the response failed.
+    ///
+    /// ```
+    /// use axum_htmx::response::HxResponseTrigger;
+    /// use serde_json::json;
+    ///
+    /// let trigger = HxResponseTrigger::lazy("cartUpdated", || json!({"items": 3}));
+    /// ```
+    pub fn lazy<F>(name: impl AsRef<str>, payload: F) -> HxLazyTrigger<F>
+    where
+        F: FnOnce() -> serde_json::Value,
+    {
+        HxLazyTrigger {
+            mode: TriggerMode::Normal,
+            name: name.as_ref().to_owned(),
+            payload,
+        }
+    }
+}
+
+/// The `HX-Trigger*` header with a lazily computed payload.
+///
+/// Created by [`HxResponseTrigger::lazy`]. The payload is computed exactly once
+/// when the trigger is applied to a response, and then behaves like an
+/// [`HxResponseTrigger`] with a single event.
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+#[derive(Clone)]
+pub struct HxLazyTrigger<F> {
+    mode: TriggerMode,
+    name: String,
+    payload: F,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable", doc(cfg(feature = "serde")))]
+impl<F> HxLazyTrigger<F> {
+    /// Sets when the event should be triggered.
+    pub fn mode(mut self, mode: TriggerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F> std::fmt::Debug for HxLazyTrigger<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HxLazyTrigger")
+            .field("mode", &self.mode)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F> IntoResponseParts for HxLazyTrigger<F>
+where
+    F: FnOnce() -> serde_json::Value,
+{
+    type Error = HxError;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let event = HxEvent {
+            name: self.name,
+            data: Some((self.payload)()),
+        };
+
+        HxResponseTrigger::new(self.mode, [event]).into_response_parts(res)
+    }
+}
+
+/// The `HX-Trigger*` header with string data, available without `serde`.
+///
+/// Every event carries a single string as its data, which is enough for the
+/// common case of e.g. a message to show in a toast. The JSON object is written
+/// by hand, escaping quotes, backslashes and control characters. Non-ASCII
+/// characters are escaped as well so that the header value stays visible
+/// ASCII.
+///
+/// Unlike [`HxResponseTrigger`], this replaces any existing header for the same
+/// mode.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use axum_htmx::response::{HxStringTrigger, TriggerMode};
+///
+/// let events = HashMap::from([("show-toast".to_owned(), "Saved".to_owned())]);
+/// let trigger = HxStringTrigger::new(TriggerMode::Normal, events);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HxStringTrigger {
+    pub mode: TriggerMode,
+    pub events: Vec<(String, String)>,
+}
+
+impl HxStringTrigger {
+    /// Creates new trigger with specified mode and events with their data.
+    pub fn new<K, V>(mode: TriggerMode, events: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            mode,
+            events: events
+                .into_iter()
+                .map(|(name, data)| (name.into(), data.into()))
+                .collect(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = String::from("{");
+
+        for (i, (name, data)) in self.events.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            push_json_string(&mut json, name);
+            json.push(':');
+            push_json_string(&mut json, data);
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+impl From<std::collections::HashMap<String, String>> for HxStringTrigger {
+    fn from(events: std::collections::HashMap<String, String>) -> Self {
+        Self::new(TriggerMode::Normal, events)
+    }
+}
+
+impl IntoResponseParts for HxStringTrigger {
+    type Error = HxError;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if !self.events.is_empty() {
+            res.headers_mut().insert(
+                self.mode.header_name(),
+                http::HeaderValue::from_maybe_shared(self.to_json())?,
+            );
+            crate::response::AppliedHtmxHeaders::record(&mut res, self.mode.header_name());
         }
 
         Ok(res)
     }
 }
 
+fn push_json_string(out: &mut String, value: &str) {
+    use std::fmt::Write;
+
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ' '..='~' => out.push(c),
+            _ => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    write!(out, "\\u{unit:04x}").expect("writing to a string can't fail");
+                }
+            }
+        }
+    }
+    out.push('"');
+}
+
 #[cfg(test)]
 mod tests {
     use http::HeaderValue;
-    use serde_json::json;
 
     use super::*;
 
     #[test]
+    #[cfg(feature = "simd-json")]
+    fn simd_json_matches_serde_json() {
+        use axum_core::response::IntoResponse;
+        use serde_json::json;
+
+        let payloads = [
+            json!(null),
+            json!({"level": "info", "message": {"title": "Hello", "body": "World"}}),
+            json!([1, -2, 3.5, 0.1, u64::MAX, i64::MIN]),
+            json!("quotes \" and \\ backslashes, / slash, \u{1} control, \n newline"),
+            json!({"unicode": "héllo wörld ✓", "emoji": "🎉"}),
+            json!((0..100)
+                .map(|i| json!({"id": i, "tags": ["a", "b"]}))
+                .collect::<Vec<_>>()),
+        ];
+
+        for payload in payloads {
+            assert_eq!(
+                to_json_string(&payload).unwrap(),
+                serde_json::to_string(&payload).unwrap()
+            );
+
+            let trigger = HxResponseTrigger::normal([
+                HxEvent::new("first"),
+                HxEvent::new_with_data("second", payload.clone()).unwrap(),
+            ]);
+            let (parts, _) = (trigger, ()).into_response().into_parts();
+            let expected = serde_json::to_string(&json!({"first": null, "second": payload}));
+            assert_eq!(parts.headers[crate::HX_TRIGGER], expected.unwrap());
+        }
+
+        // simd-json writes exponents without a `+`, which parses the same.
+        let large = json!(1e300);
+        assert_eq!(to_json_string(&large).unwrap(), "1e300");
+        assert_eq!(serde_json::to_string(&large).unwrap(), "1e+300");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn event_serialization_error_names_event() {
+        struct Failing;
+
+        impl ::serde::Serialize for Failing {
+            fn serialize<S: ::serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(::serde::ser::Error::custom("not serializable"))
+            }
+        }
+
+        let err = HxEvent::new_with_data("report-ready", Failing).unwrap_err();
+        let HxError::TriggerSerialization { event, source } = &err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(event, "report-ready");
+        assert_eq!(source.to_string(), "not serializable");
+        assert_eq!(
+            err.to_string(),
+            "Failed to serialize data of event `report-ready`: not serializable"
+        );
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn lazy_trigger_payload_is_computed_on_apply() {
+        use std::cell::Cell;
+
+        use axum_core::response::IntoResponse;
+        use serde_json::json;
+
+        let calls = Cell::new(0);
+        let trigger = HxResponseTrigger::lazy("cartUpdated", || {
+            calls.set(calls.get() + 1);
+            json!({"items": 3})
+        })
+        .mode(TriggerMode::AfterSettle);
+        assert_eq!(calls.get(), 0);
+
+        let res = (trigger, "").into_response();
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER_AFTER_SETTLE).unwrap(),
+            r#"{"cartUpdated":{"items":3}}"#
+        );
+
+        // The payload is never computed if an earlier part fails.
+        let trigger = HxResponseTrigger::lazy("cartUpdated", || {
+            calls.set(calls.get() + 1);
+            json!(null)
+        });
+        let res = (
+            crate::response::HxRetarget::from("bad\nselector"),
+            trigger,
+            "",
+        )
+            .into_response();
+        assert!(res.status().is_server_error());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
     fn valid_event_to_header_encoding() {
+        use serde_json::json;
+
         let evt = HxEvent::new_with_data(
             "my-event",
             json!({"level": "info", "message": {
@@ -215,4 +704,232 @@ mod tests {
             events_to_header_value(HxResponseTrigger::normal(["foo", "bar"]).events).unwrap();
         assert_eq!(value, HeaderValue::from_static("foo, bar"));
     }
+
+    #[test]
+    fn string_trigger_escapes_values() {
+        use axum_core::response::IntoResponse;
+
+        let trigger = HxStringTrigger::new(
+            TriggerMode::Normal,
+            [
+                ("quote", r#"say "hi""#),
+                ("path", r"C:\temp"),
+                ("lines", "a\nb\u{1}é"),
+            ],
+        );
+        assert_eq!(
+            trigger.to_json(),
+            r#"{"quote":"say \"hi\"","path":"C:\\temp","lines":"a\nb\u0001\u00e9"}"#
+        );
+
+        let res = (trigger, "").into_response();
+        assert!(res.headers().get(headers::HX_TRIGGER).is_some());
+
+        let trigger = HxStringTrigger::new(TriggerMode::AfterSwap, [("done", "")]);
+        let res = (trigger, "").into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER_AFTER_SWAP).unwrap(),
+            r#"{"done":""}"#
+        );
+
+        let trigger = HxStringTrigger::new(TriggerMode::Normal, Vec::<(String, String)>::new());
+        let res = (trigger, "").into_response();
+        assert!(res.headers().get(headers::HX_TRIGGER).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn string_trigger_is_valid_json() {
+        let trigger = HxStringTrigger::new(
+            TriggerMode::Normal,
+            [("msg", "\"quoted\" \\ back\tslash 🎉")],
+        );
+        let value = serde_json::from_str::<serde_json::Value>(&trigger.to_json()).unwrap();
+        assert_eq!(value["msg"], "\"quoted\" \\ back\tslash 🎉");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn trigger_from_tuples() {
+        use serde_json::json;
+
+        let trigger = HxResponseTrigger::from(("showToast", json!({"msg": "hi"})));
+        assert_eq!(trigger.mode, TriggerMode::Normal);
+        assert_eq!(
+            events_to_header_value(trigger.events).unwrap(),
+            r#"{"showToast":{"msg":"hi"}}"#
+        );
+
+        let trigger = HxResponseTrigger::from([
+            ("showToast", json!({"msg": "hi"})),
+            ("refresh", json!(null)),
+        ]);
+        let header = events_to_header_value(trigger.events).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(header.as_bytes()).unwrap(),
+            json!({"showToast": {"msg": "hi"}, "refresh": null})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn trigger_json_is_compact_and_ordered() {
+        use serde_json::json;
+
+        let events = vec![
+            HxEvent::new_with_data("zeta", json!({"b": 1, "a": [1, 2]})).unwrap(),
+            HxEvent::new("alpha"),
+            HxEvent::new_with_data("mid", json!("text")).unwrap(),
+            HxEvent::new_with_data("alpha", json!(true)).unwrap(),
+        ];
+
+        let value = events_to_header_value(events.clone()).unwrap();
+        assert_eq!(
+            value.as_bytes(),
+            br#"{"zeta":{"a":[1,2],"b":1},"alpha":true,"mid":"text"}"#
+        );
+        assert_eq!(events_to_header_value(events).unwrap(), value);
+    }
+
+    #[test]
+    fn sse_event_formatting() {
+        assert_eq!(
+            sse_trigger_event("refresh", "<div>new</div>").unwrap(),
+            "event: refresh\ndata: <div>new</div>\n\n"
+        );
+        assert_eq!(
+            sse_trigger_event("rows", "<tr>1</tr>\n<tr>2</tr>\r\n<tr>3</tr>").unwrap(),
+            "event: rows\ndata: <tr>1</tr>\ndata: <tr>2</tr>\ndata: <tr>3</tr>\n\n"
+        );
+        assert_eq!(
+            sse_trigger_event("ping", "").unwrap(),
+            "event: ping\ndata: \n\n"
+        );
+    }
+
+    #[test]
+    fn sse_trigger_event_line_breaks() {
+        // A lone `\r` is a line terminator in SSE as well.
+        assert_eq!(
+            sse_trigger_event("rows", "a\rb").unwrap(),
+            "event: rows\ndata: a\ndata: b\n\n"
+        );
+        // Trailing line breaks are kept as empty data lines.
+        assert_eq!(
+            sse_trigger_event("rows", "a\n").unwrap(),
+            "event: rows\ndata: a\ndata: \n\n"
+        );
+        assert_eq!(
+            sse_trigger_event("rows", "a\r\n\r\n").unwrap(),
+            "event: rows\ndata: a\ndata: \ndata: \n\n"
+        );
+
+        for name in ["rows\nid: 1", "rows\rretry: 0", "rows\r\n"] {
+            assert!(matches!(
+                sse_trigger_event(name, "data"),
+                Err(HxError::InvalidSseEvent(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn after_swap_trigger_uses_after_swap_header() {
+        use axum_core::response::IntoResponse;
+
+        let res = (HxResponseTrigger::after_swap(["swapped"]), "").into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER_AFTER_SWAP).unwrap(),
+            "swapped"
+        );
+        assert!(res
+            .headers()
+            .get(headers::HX_TRIGGER_AFTER_SETTLE)
+            .is_none());
+
+        let res = (HxResponseTrigger::after_settle(["settled"]), "").into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER_AFTER_SETTLE).unwrap(),
+            "settled"
+        );
+        assert!(res.headers().get(headers::HX_TRIGGER_AFTER_SWAP).is_none());
+    }
+
+    #[test]
+    fn empty_trigger_adds_no_header() {
+        use axum_core::response::IntoResponse;
+
+        let trigger = HxResponseTrigger::normal(Vec::<HxEvent>::new());
+        assert!(trigger.is_empty());
+
+        let res = (trigger, "").into_response();
+        assert!(res.headers().get(headers::HX_TRIGGER).is_none());
+    }
+
+    #[test]
+    fn triggers_merge_into_existing_header() {
+        use axum_core::response::IntoResponse;
+
+        let res = (
+            HxResponseTrigger::normal(["first"]),
+            HxResponseTrigger::normal(["second", "third"]),
+            "",
+        )
+            .into_response();
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER).unwrap(),
+            "first, second, third"
+        );
+
+        let res = (
+            HxResponseTrigger::normal(["first"]),
+            HxResponseTrigger::after_settle(["second"]),
+            "",
+        )
+            .into_response();
+        assert_eq!(res.headers().get(headers::HX_TRIGGER).unwrap(), "first");
+        assert_eq!(
+            res.headers().get(headers::HX_TRIGGER_AFTER_SETTLE).unwrap(),
+            "second"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn triggers_with_data_merge_into_existing_header() {
+        use axum_core::response::IntoResponse;
+        use serde_json::{json, Value};
+
+        let res = (
+            HxResponseTrigger::normal(["first"]),
+            HxResponseTrigger::normal(
+                [HxEvent::new_with_data("second", json!({"id": 1})).unwrap()],
+            ),
+            HxResponseTrigger::normal(["third"]),
+            "",
+        )
+            .into_response();
+
+        let header = res.headers().get(headers::HX_TRIGGER).unwrap();
+        let value = serde_json::from_slice::<Value>(header.as_bytes()).unwrap();
+        assert_eq!(
+            value,
+            json!({"first": null, "second": {"id": 1}, "third": null})
+        );
+    }
+
+    #[test]
+    fn into_hx_event_collects_into_trigger() {
+        struct Saved(u32);
+
+        impl IntoHxEvent for Saved {
+            fn into_hx_event(self) -> HxEvent {
+                HxEvent::new(format!("saved-{}", self.0))
+            }
+        }
+
+        let trigger =
+            HxResponseTrigger::normal([Saved(1), Saved(2)].map(IntoHxEvent::into_hx_event));
+        let value = events_to_header_value(trigger.events).unwrap();
+        assert_eq!(value, HeaderValue::from_static("saved-1, saved-2"));
+    }
 }
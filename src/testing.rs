@@ -0,0 +1,82 @@
+//! Helpers for testing htmx endpoints with `axum-test`.
+
+use axum_test::TestRequest;
+
+use crate::{HX_BOOSTED, HX_REQUEST, HX_TRIGGER};
+
+/// Extension methods for [`TestRequest`] that set htmx request headers.
+///
+/// ```
+/// use axum::{routing::get, Router};
+/// use axum_htmx::{extract::HxRequest, testing::HtmxTestRequest};
+/// use axum_test::TestServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// async fn handler(HxRequest(hx): HxRequest) -> String {
+///     hx.to_string()
+/// }
+///
+/// let app = Router::new().route("/", get(handler));
+/// let server = TestServer::new(app).unwrap();
+///
+/// server.get("/").htmx().await.assert_text("true");
+/// # }
+/// ```
+pub trait HtmxTestRequest {
+    /// Sets the `HX-Request` header, as sent by htmx on every request.
+    fn htmx(self) -> Self;
+
+    /// Sets the `HX-Boosted` header together with `HX-Request`, as sent by
+    /// htmx for boosted elements.
+    fn boosted(self) -> Self;
+
+    /// Sets the `HX-Trigger` header to the `id` of the triggering element.
+    fn hx_trigger(self, id: impl AsRef<str>) -> Self;
+}
+
+impl HtmxTestRequest for TestRequest {
+    fn htmx(self) -> Self {
+        self.add_header(HX_REQUEST, "true")
+    }
+
+    fn boosted(self) -> Self {
+        self.htmx().add_header(HX_BOOSTED, "true")
+    }
+
+    fn hx_trigger(self, id: impl AsRef<str>) -> Self {
+        self.add_header(HX_TRIGGER, id.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{routing::get, Router};
+    use axum_test::TestServer;
+
+    use super::*;
+    use crate::extract::{HxBoosted, HxRequest, HxTrigger};
+
+    async fn echo(
+        HxRequest(request): HxRequest,
+        HxBoosted(boosted): HxBoosted,
+        HxTrigger(trigger): HxTrigger,
+    ) -> String {
+        format!("{request} {boosted} {}", trigger.unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_request_helpers() {
+        let server = TestServer::new(Router::new().route("/", get(echo))).unwrap();
+
+        server.get("/").await.assert_text("false false ");
+        server.get("/").htmx().await.assert_text("true false ");
+        server.get("/").boosted().await.assert_text("true true ");
+        server
+            .get("/")
+            .htmx()
+            .hx_trigger("save-button")
+            .await
+            .assert_text("true false save-button");
+    }
+}